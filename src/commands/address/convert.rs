@@ -0,0 +1,68 @@
+use clap::Parser;
+use subxt::utils::AccountId32;
+
+use crate::{
+    commands::error::CommandError,
+    config,
+    keystore::wallet,
+    subtensor::address::{decode_ss58, encode_ss58, parse_public_key, SS58_PREFIX},
+};
+
+#[derive(Debug, Parser)]
+pub struct ConvertArgs {
+    #[arg(
+        long = "address",
+        value_name = "ADDRESS",
+        help = "SS58 address of any network, 0x hex public key or wallet name"
+    )]
+    pub address: String,
+
+    #[arg(
+        long = "ss58-format",
+        value_name = "PREFIX",
+        default_value_t = SS58_PREFIX,
+        help = "SS58 prefix to encode the address with"
+    )]
+    pub ss58_format: u16,
+}
+
+pub fn convert(config: &config::Config, args: &ConvertArgs) -> Result<(), CommandError> {
+    let (account, prefix) = decode_address(config, &args.address)?;
+
+    if let Some(prefix) = prefix {
+        println!("Input prefix: {prefix}");
+    }
+    println!("Public key:   0x{}", hex::encode(account.0));
+    println!(
+        "SS58 ({SS58_PREFIX}):    {}",
+        encode_ss58(&account, SS58_PREFIX)
+    );
+    if args.ss58_format != SS58_PREFIX {
+        println!(
+            "SS58 ({}): {}",
+            args.ss58_format,
+            encode_ss58(&account, args.ss58_format)
+        );
+    }
+
+    Ok(())
+}
+
+/// account behind `address` and the SS58 prefix it was written with, if any
+fn decode_address(
+    config: &config::Config,
+    address: &str,
+) -> Result<(AccountId32, Option<u16>), CommandError> {
+    let address = address.trim();
+
+    if address.starts_with("0x") {
+        let account = parse_public_key(address).map_err(CommandError::Input)?;
+        return Ok((account, None));
+    }
+
+    match decode_ss58(address) {
+        Ok((account, prefix)) => Ok((account, Some(prefix))),
+        Err(e) if !config.key_path.join(address).is_dir() => Err(CommandError::Input(e)),
+        Err(_) => Ok((wallet::coldkey_account_id(&config.key_path, address)?, None)),
+    }
+}
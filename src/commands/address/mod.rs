@@ -0,0 +1,13 @@
+pub mod convert;
+
+use clap::Subcommand;
+
+use self::convert::ConvertArgs;
+
+/// commands for inspecting account addresses
+#[derive(Debug, Subcommand)]
+pub enum AddressCommands {
+    /// Re-encode an address between SS58 formats and hex
+    #[command(arg_required_else_help = true)]
+    Convert(ConvertArgs),
+}
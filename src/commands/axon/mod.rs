@@ -0,0 +1,18 @@
+pub mod serve;
+pub mod show;
+
+use clap::Subcommand;
+
+use self::{serve::ServeAxonArgs, show::ShowAxonArgs};
+
+/// commands for the axon a hotkey announces on a subnet
+#[derive(Debug, Subcommand)]
+pub enum AxonCommands {
+    /// Announce the axon endpoint of a hotkey
+    #[command(arg_required_else_help = true)]
+    Serve(ServeAxonArgs),
+
+    /// Show the axon and prometheus endpoints stored for a hotkey
+    #[command(arg_required_else_help = true)]
+    Show(ShowAxonArgs),
+}
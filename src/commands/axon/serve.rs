@@ -0,0 +1,209 @@
+use std::net::IpAddr;
+
+use clap::Parser;
+use dialoguer::Confirm;
+use subxt::{OnlineClient, SubstrateConfig};
+use subxt_signer::sr25519::Keypair;
+
+use crate::{
+    commands::{error::CommandError, submit::preview},
+    config,
+    keystore::error::KeystoreError,
+    subtensor::{address::Address, block::At, interface::api, net::ip_to_int, queries},
+};
+
+#[derive(Debug, Parser)]
+pub struct ServeAxonArgs {
+    #[arg(long = "netuid", value_name = "NETUID", help = "Subnet to serve on")]
+    pub netuid: u16,
+
+    #[arg(
+        long = "ip",
+        value_name = "IP",
+        help = "IPv4 or IPv6 address of the axon"
+    )]
+    pub ip: IpAddr,
+
+    #[arg(long = "port", value_name = "PORT", help = "Port of the axon")]
+    pub port: u16,
+
+    #[arg(
+        long = "protocol",
+        value_name = "INTEGER",
+        default_value_t = 4,
+        help = "Protocol announced with the axon"
+    )]
+    pub protocol: u8,
+
+    #[arg(
+        long = "version",
+        value_name = "INTEGER",
+        default_value_t = 0,
+        help = "Version announced with the axon"
+    )]
+    pub version: u32,
+
+    // coldkey
+    #[arg(
+        long = "wallet",
+        value_name = "STRING",
+        help = "Specifies the coldkey wallet the hotkey belongs to"
+    )]
+    pub wallet: Option<String>,
+
+    // hotkey name under the wallet or ss58 address
+    #[arg(
+        long = "hotkey",
+        value_name = "STRING",
+        help = "Name of a hotkey under the wallet or its SS58 address, serving the axon"
+    )]
+    pub hotkey: Option<Address>,
+}
+
+pub async fn serve_axon(config: &config::Config, args: &ServeAxonArgs) -> Result<(), CommandError> {
+    let (client, signer) = serving_hotkey(config, &args.wallet, &args.hotkey, args.netuid).await?;
+    let hotkey = signer.public_key().to_account_id();
+
+    let last_served = queries::axon(&client, At::Latest, args.netuid, &hotkey)
+        .await?
+        .map(|axon| axon.block)
+        .unwrap_or_default();
+    check_serving_rate_limit(&client, args.netuid, last_served).await?;
+
+    let (ip, ip_type) = ip_to_int(args.ip);
+
+    println!("Hotkey:   {hotkey}");
+    println!("Subnet:   {}", args.netuid);
+    println!("Axon:     {}", endpoint(args.ip, args.port));
+    println!("Protocol: {}", args.protocol);
+    println!("Version:  {}", args.version);
+
+    let serve_axon_tx = api::tx().subtensor_module().serve_axon(
+        args.netuid,
+        args.version,
+        ip,
+        args.port,
+        ip_type,
+        args.protocol,
+        0,
+        0,
+    );
+
+    if config.dry_run {
+        preview(&client, &serve_axon_tx, &signer).await?;
+        println!("Dry run, nothing submitted");
+        return Ok(());
+    }
+
+    let confirmed = Confirm::new()
+        .with_prompt("Announce this axon?")
+        .default(false)
+        .interact()
+        .map_err(KeystoreError::DialogueError)?;
+
+    if !confirmed {
+        println!("Serving cancelled");
+        return Ok(());
+    }
+
+    let events = client
+        .tx()
+        .sign_and_submit_then_watch_default(&serve_axon_tx, &signer)
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    if let Some(event) = events.find_first::<api::subtensor_module::events::AxonServed>()? {
+        println!("Axon served for {} on subnet {}", event.1, event.0);
+    }
+
+    Ok(())
+}
+
+/// connects and loads the hotkey that signs serve calls, which must be registered on `netuid`
+pub async fn serving_hotkey(
+    config: &config::Config,
+    wallet: &Option<String>,
+    hotkey: &Option<Address>,
+    netuid: u16,
+) -> Result<(OnlineClient<SubstrateConfig>, Keypair), CommandError> {
+    let wallet_name = wallet
+        .clone()
+        .or_else(|| config.default_coldkey.clone())
+        .ok_or_else(|| CommandError::Input("No wallet specified".into()))?;
+    let hotkey_name = hotkey
+        .clone()
+        .or_else(|| config.default_hotkey.clone().map(Address::Name))
+        .ok_or_else(|| CommandError::Input("No hotkey specified".into()))?;
+
+    let signer = hotkey_name
+        .hotkey_keystore(&config.key_path, &wallet_name)?
+        .to_keypair()?;
+    let hotkey = signer.public_key().to_account_id();
+
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+
+    if queries::uid(&client, At::Latest, netuid, &hotkey)
+        .await?
+        .is_none()
+    {
+        return Err(CommandError::Input(format!(
+            "Hotkey {hotkey_name} is not registered on subnet {netuid}"
+        )));
+    }
+
+    Ok((client, signer))
+}
+
+/// refuses to serve again before `serving_rate_limit` blocks have passed since `last_served`
+pub async fn check_serving_rate_limit(
+    client: &OnlineClient<SubstrateConfig>,
+    netuid: u16,
+    last_served: u64,
+) -> Result<(), CommandError> {
+    let rate_limit = queries::serving_rate_limit(client, At::Latest, netuid).await?;
+    let current_block = u64::from(client.blocks().at_latest().await?.number());
+
+    if last_served > 0 && current_block < last_served.saturating_add(rate_limit) {
+        return Err(CommandError::Input(format!(
+            "Served at block {last_served}, the rate limit allows serving again at block {}",
+            last_served.saturating_add(rate_limit)
+        )));
+    }
+
+    Ok(())
+}
+
+/// `ip:port`, with brackets around IPv6 addresses
+pub fn endpoint(ip: IpAddr, port: u16) -> String {
+    match ip {
+        IpAddr::V4(ip) => format!("{ip}:{port}"),
+        IpAddr::V6(ip) => format!("[{ip}]:{port}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serve_axon_args() {
+        let args = ServeAxonArgs::try_parse_from([
+            "serve", "--netuid", "1", "--ip", "::1", "--port", "8091",
+        ])
+        .unwrap();
+        assert_eq!(args.protocol, 4);
+        assert_eq!(endpoint(args.ip, args.port), "[::1]:8091");
+
+        assert!(ServeAxonArgs::try_parse_from([
+            "serve",
+            "--netuid",
+            "1",
+            "--ip",
+            "300.1.1.1",
+            "--port",
+            "8091",
+        ])
+        .is_err());
+    }
+}
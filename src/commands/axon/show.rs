@@ -0,0 +1,86 @@
+use clap::Parser;
+use subxt::{OnlineClient, SubstrateConfig};
+
+use crate::{
+    commands::{axon::serve::endpoint, block::BlockArgs, error::CommandError},
+    config,
+    subtensor::{address::Address, net::int_to_ip, queries},
+};
+
+#[derive(Debug, Parser)]
+pub struct ShowAxonArgs {
+    #[arg(long = "netuid", value_name = "NETUID", help = "Subnet to look on")]
+    pub netuid: u16,
+
+    // coldkey
+    #[arg(
+        long = "wallet",
+        value_name = "STRING",
+        help = "Specifies the coldkey wallet the hotkey belongs to"
+    )]
+    pub wallet: Option<String>,
+
+    // hotkey name under the wallet or ss58 address
+    #[arg(
+        long = "hotkey",
+        value_name = "STRING",
+        help = "Name of a hotkey under the wallet or its SS58 address"
+    )]
+    pub hotkey: Option<Address>,
+
+    #[command(flatten)]
+    pub block: BlockArgs,
+}
+
+pub async fn show_axon(config: &config::Config, args: &ShowAxonArgs) -> Result<(), CommandError> {
+    let wallet_name = args
+        .wallet
+        .clone()
+        .or_else(|| config.default_coldkey.clone())
+        .unwrap_or_default();
+    let hotkey = args
+        .hotkey
+        .clone()
+        .or_else(|| config.default_hotkey.clone().map(Address::Name))
+        .ok_or_else(|| CommandError::Input("No hotkey specified".into()))?;
+    let hotkey = hotkey.hotkey_account_id(&config.key_path, &wallet_name)?;
+
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+    let at = args.block.at(config, &client).await?;
+
+    println!("Hotkey: {hotkey}");
+    println!("Subnet: {}", args.netuid);
+
+    match queries::axon(&client, at, args.netuid, &hotkey).await? {
+        Some(axon) => {
+            let address = int_to_ip(axon.ip, axon.ip_type)
+                .map(|ip| endpoint(ip, axon.port))
+                .unwrap_or_else(|| format!("invalid ip {} of type {}", axon.ip, axon.ip_type));
+            println!(
+                "Axon:       {address}, protocol {}, version {}, served at block {}",
+                axon.protocol, axon.version, axon.block
+            );
+        }
+        None => println!("Axon:       not served"),
+    }
+
+    match queries::prometheus(&client, at, args.netuid, &hotkey).await? {
+        Some(prometheus) => {
+            let address = int_to_ip(prometheus.ip, prometheus.ip_type)
+                .map(|ip| endpoint(ip, prometheus.port))
+                .unwrap_or_else(|| {
+                    format!(
+                        "invalid ip {} of type {}",
+                        prometheus.ip, prometheus.ip_type
+                    )
+                });
+            println!(
+                "Prometheus: {address}, version {}, served at block {}",
+                prometheus.version, prometheus.block
+            );
+        }
+        None => println!("Prometheus: not served"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use clap::Args;
+use subxt::{OnlineClient, SubstrateConfig};
+
+use crate::{
+    commands::error::CommandError,
+    config,
+    subtensor::{
+        block::{At, BlockId},
+        queries,
+    },
+};
+
+/// `--block` for commands that only read chain state
+#[derive(Debug, Args)]
+pub struct BlockArgs {
+    #[arg(
+        long = "block",
+        value_name = "NUMBER|HASH",
+        help = "Read the state at this block instead of the latest one"
+    )]
+    pub block: Option<BlockId>,
+}
+
+impl BlockArgs {
+    pub async fn at(
+        &self,
+        config: &config::Config,
+        client: &OnlineClient<SubstrateConfig>,
+    ) -> Result<At, CommandError> {
+        resolve_block(config, client, self.block).await
+    }
+}
+
+/// resolves a block number to its hash through `system().block_hash`, falling back to the node's
+/// RPC for blocks older than the 4096 the runtime keeps
+pub async fn resolve_block(
+    config: &config::Config,
+    client: &OnlineClient<SubstrateConfig>,
+    block: Option<BlockId>,
+) -> Result<At, CommandError> {
+    let number = match block {
+        None => return Ok(At::Latest),
+        Some(BlockId::Hash(hash)) => return Ok(At::Block(hash)),
+        Some(BlockId::Number(number)) => number,
+    };
+
+    let hash = match queries::block_hash(client, number).await? {
+        Some(hash) => Some(hash),
+        None => queries::block_hash_from_rpc(&config.subtensor_endpoint, number).await?,
+    };
+
+    hash.map(At::Block).ok_or_else(|| {
+        CommandError::Input(format!(
+            "Block {number} is unknown to the node, pass its hash or use an archive node"
+        ))
+    })
+}
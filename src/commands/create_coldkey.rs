@@ -28,7 +28,7 @@ pub fn create_new_coldkey(
     let words = keystore::validate_wordcount(args.num_words)?;
 
     let mnemonic = Mnemonic::generate(words)
-        .map_err(|e| CommandError::Input(format!("Mnemonic generation failed: {e}").into()))?;
+        .map_err(|e| CommandError::Input(format!("Mnemonic generation failed: {e}")))?;
 
     let name = args.keystore_params.read_name()?;
 
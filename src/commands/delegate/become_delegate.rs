@@ -0,0 +1,122 @@
+use clap::Parser;
+use dialoguer::Confirm;
+use subxt::{OnlineClient, SubstrateConfig};
+
+use crate::{
+    commands::{error::CommandError, submit::preview},
+    config,
+    keystore::{
+        cli::{unlock_coldkey, PasswordArgs},
+        error::KeystoreError,
+    },
+    subtensor::{
+        address::Address,
+        block::At,
+        interface::api,
+        queries::{self, u16_to_fraction},
+    },
+};
+
+#[derive(Debug, Parser)]
+pub struct BecomeDelegateArgs {
+    // coldkey
+    #[arg(
+        long = "wallet",
+        value_name = "STRING",
+        help = "Specifies the coldkey wallet that owns the hotkey"
+    )]
+    pub wallet: Option<String>,
+
+    // hotkey name under the wallet or ss58 address
+    #[arg(
+        long = "hotkey",
+        value_name = "STRING",
+        help = "Name of a hotkey under the wallet or its SS58 address"
+    )]
+    pub hotkey: Option<Address>,
+
+    #[command(flatten)]
+    pub password_params: PasswordArgs,
+}
+
+pub async fn become_delegate(
+    config: &config::Config,
+    args: &BecomeDelegateArgs,
+) -> Result<(), CommandError> {
+    let coldkey = args
+        .wallet
+        .clone()
+        .or_else(|| config.default_coldkey.clone())
+        .ok_or_else(|| CommandError::Input("No coldkey specified".into()))?;
+    let hotkey = args
+        .hotkey
+        .clone()
+        .or_else(|| config.default_hotkey.clone().map(Address::Name))
+        .ok_or_else(|| CommandError::Input("No hotkey specified".into()))?;
+    let hotkey = hotkey.hotkey_account_id(&config.key_path, &coldkey)?;
+
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+
+    if let Some(take) = queries::delegate_take(&client, At::Latest, &hotkey).await? {
+        println!(
+            "Hotkey {hotkey} is already a delegate with take {:.2}%",
+            u16_to_fraction(take) * 100.0
+        );
+        return Ok(());
+    }
+
+    let keystore = unlock_coldkey(
+        &config.key_path.join(&coldkey),
+        args.password_params.password.clone(),
+    )?;
+    let signer = keystore.to_keypair()?;
+    let account_id = signer.public_key().to_account_id();
+
+    if queries::owner(&client, At::Latest, &hotkey).await?.as_ref() != Some(&account_id) {
+        return Err(CommandError::Input(format!(
+            "Hotkey {hotkey} is not owned by {account_id}"
+        )));
+    }
+
+    let take = queries::default_take(&client, At::Latest).await?;
+
+    println!("Coldkey: {coldkey} ({account_id})");
+    println!("Hotkey:  {hotkey}");
+    println!("Take:    {:.2}%", u16_to_fraction(take) * 100.0);
+
+    let become_delegate_tx = api::tx().subtensor_module().become_delegate(hotkey);
+
+    if config.dry_run {
+        preview(&client, &become_delegate_tx, &signer).await?;
+        println!("Dry run, nothing submitted");
+        return Ok(());
+    }
+
+    let confirmed = Confirm::new()
+        .with_prompt("Make this hotkey a delegate?")
+        .default(false)
+        .interact()
+        .map_err(KeystoreError::DialogueError)?;
+
+    if !confirmed {
+        println!("Delegation cancelled");
+        return Ok(());
+    }
+
+    let events = client
+        .tx()
+        .sign_and_submit_then_watch_default(&become_delegate_tx, &signer)
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    if let Some(event) = events.find_first::<api::subtensor_module::events::DelegateAdded>()? {
+        println!(
+            "Hotkey {} is now a delegate with take {:.2}%",
+            event.1,
+            u16_to_fraction(event.2) * 100.0
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use clap::Parser;
+use subxt::{OnlineClient, SubstrateConfig};
+
+use crate::{
+    commands::{block::BlockArgs, error::CommandError, table::render_table},
+    config,
+    subtensor::{
+        address::Address,
+        queries::{self, u16_to_fraction},
+        units::Balance,
+    },
+};
+
+#[derive(Debug, Parser)]
+pub struct ListDelegatesArgs {
+    // coldkey
+    #[arg(
+        long = "wallet",
+        value_name = "STRING",
+        help = "Coldkey wallet or SS58 address whose positions are shown"
+    )]
+    pub wallet: Option<Address>,
+
+    #[command(flatten)]
+    pub block: BlockArgs,
+}
+
+pub async fn list_delegates(
+    config: &config::Config,
+    args: &ListDelegatesArgs,
+) -> Result<(), CommandError> {
+    // our positions are shown when a coldkey is given or configured
+    let coldkey = match args
+        .wallet
+        .clone()
+        .or_else(|| config.default_coldkey.clone().map(Address::Name))
+    {
+        Some(wallet) => Some(wallet.coldkey_account_id(&config.key_path)?),
+        None => None,
+    };
+
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+    let at = args.block.at(config, &client).await?;
+
+    let default_take = queries::default_take(&client, at).await?;
+
+    let mut delegates = Vec::new();
+    for (hotkey, take) in queries::delegates(&client, at).await? {
+        let total_stake = queries::total_hotkey_stake(&client, at, &hotkey).await?;
+        let our_stake = match &coldkey {
+            Some(coldkey) => Some(queries::stake(&client, at, &hotkey, coldkey).await?),
+            None => None,
+        };
+        delegates.push((hotkey, take, total_stake, our_stake));
+    }
+    delegates.sort_by_key(|delegate| std::cmp::Reverse(delegate.2));
+
+    let mut headers = vec!["HOTKEY", "TAKE", "TOTAL STAKE"];
+    if coldkey.is_some() {
+        headers.push("OUR STAKE");
+    }
+
+    let our_total = Balance::checked_sum(delegates.iter().filter_map(|delegate| delegate.3))
+        .ok_or_else(|| CommandError::Input("Delegated stake overflows".into()))?;
+    let rows: Vec<_> = delegates
+        .into_iter()
+        .map(|(hotkey, take, total_stake, our_stake)| {
+            let mut row = vec![
+                hotkey.to_string(),
+                format!("{:.2}%", u16_to_fraction(take) * 100.0),
+                total_stake.to_string(),
+            ];
+            if let Some(our_stake) = our_stake {
+                row.push(our_stake.to_string());
+            }
+            row
+        })
+        .collect();
+
+    println!(
+        "{} delegates, default take {:.2}%",
+        rows.len(),
+        u16_to_fraction(default_take) * 100.0
+    );
+    print!("{}", render_table(&headers, &rows));
+    if let Some(coldkey) = coldkey {
+        println!("Delegated by {coldkey}: {our_total}");
+    }
+
+    Ok(())
+}
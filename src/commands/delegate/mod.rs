@@ -0,0 +1,27 @@
+pub mod become_delegate;
+pub mod list;
+pub mod nominate;
+
+use clap::Subcommand;
+
+use self::{become_delegate::BecomeDelegateArgs, list::ListDelegatesArgs};
+use crate::commands::stake::StakeArgs;
+
+/// commands for delegates and the coldkeys nominating them
+#[derive(Debug, Subcommand)]
+pub enum DelegateCommands {
+    /// Open a hotkey to nominations from other coldkeys
+    #[command(arg_required_else_help = true)]
+    Become(BecomeDelegateArgs),
+
+    /// Show all delegates with their take and total stake
+    List(ListDelegatesArgs),
+
+    /// Stake from the coldkey onto a delegate's hotkey
+    #[command(arg_required_else_help = true)]
+    Nominate(StakeArgs),
+
+    /// Unstake from a delegate's hotkey back to the coldkey
+    #[command(arg_required_else_help = true)]
+    Undelegate(StakeArgs),
+}
@@ -0,0 +1,55 @@
+use subxt::{OnlineClient, SubstrateConfig};
+
+use crate::{
+    commands::{
+        error::CommandError,
+        stake::{add_stake_with_client, remove_stake_with_client, StakeArgs},
+    },
+    config,
+    subtensor::{block::At, queries},
+};
+
+/// stakes onto another coldkey's hotkey, which must have become a delegate
+pub async fn nominate(config: &config::Config, args: &StakeArgs) -> Result<(), CommandError> {
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+    check_delegate(config, &client, args).await?;
+
+    add_stake_with_client(config, &client, args).await
+}
+
+/// unstakes from a delegate's hotkey
+pub async fn undelegate(config: &config::Config, args: &StakeArgs) -> Result<(), CommandError> {
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+    check_delegate(config, &client, args).await?;
+
+    remove_stake_with_client(config, &client, args).await
+}
+
+/// checks the hotkey given to nominate or undelegate is a delegate
+async fn check_delegate(
+    config: &config::Config,
+    client: &OnlineClient<SubstrateConfig>,
+    args: &StakeArgs,
+) -> Result<(), CommandError> {
+    let coldkey = args
+        .wallet
+        .clone()
+        .or_else(|| config.default_coldkey.clone())
+        .unwrap_or_default();
+    let hotkey = args
+        .hotkey
+        .clone()
+        .ok_or_else(|| CommandError::Input("No delegate hotkey specified".into()))?;
+    let hotkey = hotkey.hotkey_account_id(&config.key_path, &coldkey)?;
+
+    if queries::delegate_take(client, At::Latest, &hotkey)
+        .await?
+        .is_none()
+    {
+        return Err(CommandError::Input(format!(
+            "Hotkey {hotkey} is not a delegate"
+        )));
+    }
+
+    Ok(())
+}
@@ -4,8 +4,8 @@ use crate::keystore::error::KeystoreError;
 
 #[derive(Error, Debug)]
 pub enum CommandError {
-    #[error("Invalid Transfer")]
-    Invalid(#[source] subxt::Error),
+    #[error("Subtensor error")]
+    Invalid(#[source] Box<subxt::Error>),
 
     #[error("Invalid input: {0}")]
     Input(String),
@@ -13,3 +13,9 @@ pub enum CommandError {
     #[error("Keyfile error")]
     KeystoreError(#[from] KeystoreError),
 }
+
+impl From<subxt::Error> for CommandError {
+    fn from(e: subxt::Error) -> Self {
+        CommandError::Invalid(Box::new(e))
+    }
+}
@@ -0,0 +1,219 @@
+use clap::Parser;
+use subxt::{OnlineClient, SubstrateConfig};
+
+use crate::{
+    commands::{block::resolve_block, error::CommandError, table::render_table},
+    config,
+    subtensor::{
+        block::{At, BlockId},
+        metagraph::{diff, DiffThresholds, NeuronChange},
+        queries,
+        units::Balance,
+    },
+};
+
+#[derive(Debug, Parser)]
+pub struct MetagraphDiffArgs {
+    #[arg(long = "netuid", value_name = "NETUID", help = "Subnet to compare")]
+    pub netuid: u16,
+
+    #[arg(
+        long = "from",
+        value_name = "NUMBER|HASH",
+        help = "Block to compare from"
+    )]
+    pub from: BlockId,
+
+    #[arg(
+        long = "to",
+        value_name = "NUMBER|HASH",
+        help = "Block to compare to, the latest one by default"
+    )]
+    pub to: Option<BlockId>,
+
+    #[arg(
+        long = "stake-threshold",
+        value_name = "TAO",
+        default_value = "1",
+        help = "Smallest stake change to report"
+    )]
+    pub stake_threshold: Balance,
+
+    #[arg(
+        long = "incentive-threshold",
+        value_name = "FRACTION",
+        default_value_t = 0.01,
+        help = "Smallest incentive change to report"
+    )]
+    pub incentive_threshold: f64,
+
+    #[arg(
+        long = "emission-threshold",
+        value_name = "TAO",
+        default_value = "0.01",
+        help = "Smallest emission change to report"
+    )]
+    pub emission_threshold: Balance,
+}
+
+pub async fn metagraph_diff(
+    config: &config::Config,
+    args: &MetagraphDiffArgs,
+) -> Result<(), CommandError> {
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+
+    let from_at = resolve_block(config, &client, Some(args.from)).await?;
+    let to_at = resolve_block(config, &client, args.to).await?;
+    check_block_order(
+        queries::block_number(&client, from_at).await?,
+        queries::block_number(&client, to_at).await?,
+    )?;
+
+    for at in [from_at, to_at] {
+        if !queries::subnets(&client, at).await?.contains(&args.netuid) {
+            return Err(CommandError::Input(format!(
+                "Subnet {} does not exist at block {}",
+                args.netuid,
+                describe(at)
+            )));
+        }
+    }
+
+    let from = queries::metagraph(&client, from_at, args.netuid).await?;
+    let to = queries::metagraph(&client, to_at, args.netuid).await?;
+
+    let thresholds = DiffThresholds {
+        stake: args.stake_threshold,
+        incentive: args.incentive_threshold,
+        emission: args.emission_threshold,
+    };
+    let changes = diff(&from, &to, &thresholds);
+
+    println!(
+        "Subnet {} from block {} ({} uids) to block {} ({} uids)",
+        args.netuid,
+        from.block,
+        from.neurons.len(),
+        to.block,
+        to.neurons.len()
+    );
+
+    if changes.is_empty() {
+        println!("No changes above the thresholds");
+        return Ok(());
+    }
+
+    let rows: Vec<Vec<String>> = changes.iter().map(change_row).collect();
+    print!(
+        "{}",
+        render_table(&["UID", "HOTKEY", "CHANGE", "FROM", "TO"], &rows)
+    );
+
+    Ok(())
+}
+
+/// the diff only reads forward in time
+fn check_block_order(from: u64, to: u64) -> Result<(), CommandError> {
+    if from >= to {
+        return Err(CommandError::Input(format!(
+            "Block {from} given to --from has to come before block {to} given to --to"
+        )));
+    }
+
+    Ok(())
+}
+
+fn describe(at: At) -> String {
+    match at {
+        At::Latest => "latest".into(),
+        At::Block(hash) => format!("{hash:?}"),
+    }
+}
+
+fn change_row(change: &NeuronChange) -> Vec<String> {
+    let uid = change.uid().to_string();
+
+    match change {
+        NeuronChange::Registered { hotkey, .. } => {
+            vec![
+                uid,
+                hotkey.to_string(),
+                "registered".into(),
+                "-".into(),
+                "-".into(),
+            ]
+        }
+        NeuronChange::Deregistered { hotkey, .. } => {
+            vec![
+                uid,
+                hotkey.to_string(),
+                "deregistered".into(),
+                "-".into(),
+                "-".into(),
+            ]
+        }
+        NeuronChange::Replaced {
+            old_hotkey,
+            new_hotkey,
+            ..
+        } => vec![
+            uid,
+            new_hotkey.to_string(),
+            "replaced".into(),
+            old_hotkey.to_string(),
+            new_hotkey.to_string(),
+        ],
+        NeuronChange::Stake {
+            hotkey, from, to, ..
+        } => vec![
+            uid,
+            hotkey.to_string(),
+            "stake".into(),
+            from.to_string(),
+            to.to_string(),
+        ],
+        NeuronChange::Incentive {
+            hotkey, from, to, ..
+        } => vec![
+            uid,
+            hotkey.to_string(),
+            "incentive".into(),
+            format!("{from:.5}"),
+            format!("{to:.5}"),
+        ],
+        NeuronChange::Emission {
+            hotkey, from, to, ..
+        } => vec![
+            uid,
+            hotkey.to_string(),
+            "emission".into(),
+            from.to_string(),
+            to.to_string(),
+        ],
+        NeuronChange::ValidatorPermit {
+            hotkey, granted, ..
+        } => vec![
+            uid,
+            hotkey.to_string(),
+            if *granted {
+                "permit granted".into()
+            } else {
+                "permit lost".into()
+            },
+            (!granted).to_string(),
+            granted.to_string(),
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_block_order() {
+        assert!(check_block_order(100, 200).is_ok());
+        assert!(check_block_order(200, 200).is_err());
+        assert!(check_block_order(200, 100).is_err());
+    }
+}
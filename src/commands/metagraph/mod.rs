@@ -0,0 +1,14 @@
+pub mod diff;
+pub mod show;
+
+use clap::Subcommand;
+
+use self::diff::MetagraphDiffArgs;
+
+/// commands comparing metagraphs, `metagraph --netuid` on its own shows one
+#[derive(Debug, Subcommand)]
+pub enum MetagraphCommands {
+    /// Report deregistrations and stake, incentive, emission and permit changes between two blocks
+    #[command(arg_required_else_help = true)]
+    Diff(MetagraphDiffArgs),
+}
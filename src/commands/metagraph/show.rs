@@ -0,0 +1,285 @@
+use std::{fs, path::PathBuf};
+
+use clap::{Parser, ValueEnum};
+use subxt::{OnlineClient, SubstrateConfig};
+
+use crate::{
+    commands::{
+        axon::serve::endpoint, block::BlockArgs, error::CommandError, metagraph::MetagraphCommands,
+        table::render_table,
+    },
+    config,
+    keystore::error::KeystoreError,
+    subtensor::{metagraph::Metagraph, queries},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum MetagraphFormat {
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Parser)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+pub struct MetagraphArgs {
+    #[command(subcommand)]
+    pub command: Option<MetagraphCommands>,
+
+    // only optional so that `metagraph diff` does not need it, clap requires it otherwise
+    #[arg(
+        long = "netuid",
+        value_name = "NETUID",
+        required = true,
+        help = "Subnet to read"
+    )]
+    pub netuid: Option<u16>,
+
+    #[arg(
+        long = "format",
+        value_enum,
+        default_value_t = MetagraphFormat::Table,
+        help = "Output format, balances are in rao for json and csv"
+    )]
+    pub format: MetagraphFormat,
+
+    #[arg(
+        long = "output",
+        value_name = "FILE",
+        help = "Write to this file instead of printing"
+    )]
+    pub output: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub block: BlockArgs,
+}
+
+pub async fn metagraph(config: &config::Config, args: &MetagraphArgs) -> Result<(), CommandError> {
+    let netuid = args
+        .netuid
+        .ok_or_else(|| CommandError::Input("No netuid specified".into()))?;
+
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+    let at = args.block.at(config, &client).await?;
+
+    if !queries::subnets(&client, at).await?.contains(&netuid) {
+        return Err(CommandError::Input(format!(
+            "Subnet {netuid} does not exist"
+        )));
+    }
+
+    let metagraph = queries::metagraph(&client, at, netuid).await?;
+
+    let rendered = format_metagraph(&metagraph, args.format)?;
+
+    match &args.output {
+        Some(path) => {
+            fs::write(path, rendered).map_err(KeystoreError::Io)?;
+            println!(
+                "Metagraph of subnet {} at block {} written to {}",
+                metagraph.netuid,
+                metagraph.block,
+                path.display()
+            );
+        }
+        None => print!("{rendered}"),
+    }
+
+    Ok(())
+}
+
+/// everything printed to stdout, or written to `--output`, for `format`
+fn format_metagraph(
+    metagraph: &Metagraph,
+    format: MetagraphFormat,
+) -> Result<String, CommandError> {
+    Ok(match format {
+        MetagraphFormat::Table => render(metagraph)?,
+        MetagraphFormat::Json => {
+            serde_json::to_string_pretty(metagraph).map_err(KeystoreError::JsonError)? + "\n"
+        }
+        MetagraphFormat::Csv => to_csv(metagraph),
+    })
+}
+
+fn render(metagraph: &Metagraph) -> Result<String, CommandError> {
+    let total_stake = metagraph
+        .total_stake()
+        .ok_or_else(|| CommandError::Input("Total stake overflows".into()))?;
+
+    let rows: Vec<Vec<String>> = metagraph
+        .neurons
+        .iter()
+        .map(|neuron| {
+            vec![
+                neuron.uid.to_string(),
+                neuron.hotkey.to_string(),
+                neuron.coldkey.to_string(),
+                neuron.stake.to_string(),
+                format!("{:.5}", neuron.rank),
+                format!("{:.5}", neuron.trust),
+                format!("{:.5}", neuron.consensus),
+                format!("{:.5}", neuron.incentive),
+                format!("{:.5}", neuron.dividends),
+                neuron.emission.to_string(),
+                format!("{:.5}", neuron.validator_trust),
+                neuron.validator_permit.to_string(),
+                neuron.last_update.to_string(),
+                neuron.active.to_string(),
+                neuron
+                    .axon
+                    .as_ref()
+                    .map(|axon| endpoint(axon.ip, axon.port))
+                    .unwrap_or_else(|| "-".into()),
+            ]
+        })
+        .collect();
+
+    Ok(format!(
+        "Subnet {} at block {}, {} uids\n{}Total stake: {}\n",
+        metagraph.netuid,
+        metagraph.block,
+        metagraph.neurons.len(),
+        render_table(
+            &[
+                "UID",
+                "HOTKEY",
+                "COLDKEY",
+                "STAKE",
+                "RANK",
+                "TRUST",
+                "CONSENSUS",
+                "INCENTIVE",
+                "DIVIDENDS",
+                "EMISSION",
+                "VTRUST",
+                "VPERMIT",
+                "UPDATED",
+                "ACTIVE",
+                "AXON",
+            ],
+            &rows,
+        ),
+        total_stake
+    ))
+}
+
+/// one line per uid, none of the fields can contain a comma or quote
+fn to_csv(metagraph: &Metagraph) -> String {
+    let mut csv = String::from(
+        "netuid,block,uid,hotkey,coldkey,stake,rank,trust,consensus,incentive,dividends,\
+         emission,validator_trust,validator_permit,last_update,active,axon_ip,axon_port\n",
+    );
+
+    for neuron in &metagraph.neurons {
+        let (ip, port) = match &neuron.axon {
+            Some(axon) => (axon.ip.to_string(), axon.port.to_string()),
+            None => (String::new(), String::new()),
+        };
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{ip},{port}\n",
+            metagraph.netuid,
+            metagraph.block,
+            neuron.uid,
+            neuron.hotkey,
+            neuron.coldkey,
+            neuron.stake.rao(),
+            neuron.rank,
+            neuron.trust,
+            neuron.consensus,
+            neuron.incentive,
+            neuron.dividends,
+            neuron.emission.rao(),
+            neuron.validator_trust,
+            neuron.validator_permit,
+            neuron.last_update,
+            neuron.active,
+        ));
+    }
+
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use subxt::utils::AccountId32;
+
+    use super::*;
+    use crate::subtensor::{metagraph::Neuron, units::Balance};
+
+    #[test]
+    fn test_metagraph_args() {
+        let args = MetagraphArgs::try_parse_from(["metagraph", "--netuid", "3"]).unwrap();
+        assert_eq!(args.netuid, Some(3));
+        assert!(args.command.is_none());
+
+        let args =
+            MetagraphArgs::try_parse_from(["metagraph", "diff", "--netuid", "3", "--from", "100"])
+                .unwrap();
+        assert!(args.netuid.is_none());
+        assert!(matches!(
+            args.command,
+            Some(MetagraphCommands::Diff(diff)) if diff.netuid == 3 && diff.to.is_none()
+        ));
+
+        assert!(MetagraphArgs::try_parse_from(["metagraph"]).is_err());
+        assert!(MetagraphArgs::try_parse_from([
+            "metagraph",
+            "--netuid",
+            "3",
+            "diff",
+            "--netuid",
+            "3",
+            "--from",
+            "100",
+        ])
+        .is_err());
+    }
+
+    fn test_metagraph() -> Metagraph {
+        let neuron = Neuron {
+            uid: 3,
+            hotkey: AccountId32([1; 32]),
+            coldkey: AccountId32([2; 32]),
+            stake: Balance::from_rao(1_500_000_000),
+            rank: 0.5,
+            trust: 0.0,
+            consensus: 0.0,
+            incentive: 0.25,
+            dividends: 0.0,
+            emission: Balance::from_rao(42),
+            validator_trust: 0.0,
+            validator_permit: true,
+            last_update: 100,
+            active: true,
+            axon: None,
+        };
+        Metagraph {
+            netuid: 1,
+            block: 200,
+            neurons: vec![neuron],
+        }
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let csv = to_csv(&test_metagraph());
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].split(',').count(), lines[1].split(',').count());
+        assert!(lines[1].starts_with("1,200,3,5C"));
+        assert!(lines[1].ends_with(",1500000000,0.5,0,0,0.25,0,42,0,true,100,true,,"));
+    }
+
+    #[test]
+    fn test_json_output_parses() {
+        let metagraph = test_metagraph();
+        let json = format_metagraph(&metagraph, MetagraphFormat::Json).unwrap();
+
+        assert_eq!(serde_json::from_str::<Metagraph>(&json).unwrap(), metagraph);
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&json).unwrap()["neurons"][0]["stake"],
+            1_500_000_000u64
+        );
+    }
+}
@@ -1,7 +1,24 @@
+pub mod address;
+pub mod axon;
+pub mod block;
 pub mod create_coldkey;
+pub mod delegate;
 pub mod error;
+pub mod metagraph;
+pub mod new_hotkey;
+pub mod prometheus;
+pub mod regen_coldkey;
+pub mod regen_coldkeypub;
+pub mod root;
 pub mod stake;
+pub mod submit;
+pub mod subnet;
+pub mod table;
 pub mod transfer;
+pub mod tx;
+pub mod update_coldkey;
+pub mod wallet;
+pub mod weights;
 
 use clap::Parser;
 use std::path::PathBuf;
@@ -35,4 +52,12 @@ pub struct CliArgs {
         help = "URL of the Subtensor endpoint"
     )]
     pub subtensor_endpoint: Option<String>,
+
+    // Dry run
+    #[arg(
+        long = "dry-run",
+        global = true,
+        help = "Sign and estimate the fee of transactions without submitting them"
+    )]
+    pub dry_run: bool,
 }
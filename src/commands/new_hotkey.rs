@@ -0,0 +1,81 @@
+use bip39::Mnemonic;
+use clap::Parser;
+use dialoguer::Confirm;
+use sp_core::crypto::Ss58AddressFormat;
+
+use crate::{
+    commands::error::CommandError,
+    config,
+    keystore::{self, cli::KeystoreArgs, Keystore},
+};
+
+#[derive(Debug, Parser)]
+pub struct NewHotkeyArgs {
+    // coldkey wallet the hotkey belongs to
+    #[arg(
+        long = "wallet",
+        value_name = "STRING",
+        help = "Specifies the coldkey wallet to store the hotkey under"
+    )]
+    pub wallet: Option<String>,
+
+    #[arg(
+        long = "length",
+        value_name = "INTEGER",
+        help = "Specifies the length of the seed phrase in number of words, value must be 12, 15, 18, 21 or 24"
+    )]
+    pub num_words: Option<usize>,
+
+    #[command(flatten)]
+    pub keystore_params: KeystoreArgs,
+}
+
+pub fn create_new_hotkey(
+    config: &config::Config,
+    args: &NewHotkeyArgs,
+) -> Result<(), CommandError> {
+    let wallet = args
+        .wallet
+        .clone()
+        .or_else(|| config.default_coldkey.clone())
+        .ok_or_else(|| CommandError::Input("No wallet specified".into()))?;
+
+    let words = keystore::validate_wordcount(args.num_words)?;
+
+    let mnemonic = Mnemonic::generate(words)
+        .map_err(|e| CommandError::Input(format!("Mnemonic generation failed: {e}")))?;
+
+    let name = args.keystore_params.read_name()?;
+    let phrase = mnemonic.words().collect::<Vec<_>>().join(" ");
+
+    // hotkeys are stored unencrypted, the same way btcli does
+    let keystore = Keystore::new::<sp_core::sr25519::Pair>(
+        &name,
+        &phrase,
+        None,
+        Some(Ss58AddressFormat::custom(42)),
+    )?;
+
+    let full_path = keystore::hotkey_path(&config.key_path, &wallet, &name);
+    if full_path.exists() {
+        let overwrite = Confirm::new()
+            .with_prompt(format!("Hotkey {name} already exists, overwrite it?"))
+            .default(false)
+            .interact()
+            .map_err(keystore::error::KeystoreError::DialogueError)?;
+
+        if !overwrite {
+            return Ok(());
+        }
+    }
+
+    if let Some(parent) = full_path.parent() {
+        keystore::create_keyfile_directory(parent)?;
+    }
+
+    println!("Saving hotkey to: {:?}", full_path);
+
+    keystore.save_unencrypted_with_secrets_to_file(&full_path)?;
+
+    Ok(())
+}
@@ -0,0 +1,13 @@
+pub mod serve;
+
+use clap::Subcommand;
+
+use self::serve::ServePrometheusArgs;
+
+/// commands for the prometheus endpoint a hotkey announces on a subnet
+#[derive(Debug, Subcommand)]
+pub enum PrometheusCommands {
+    /// Announce the prometheus endpoint of a hotkey
+    #[command(arg_required_else_help = true)]
+    Serve(ServePrometheusArgs),
+}
@@ -0,0 +1,118 @@
+use std::net::IpAddr;
+
+use clap::Parser;
+use dialoguer::Confirm;
+
+use crate::{
+    commands::{
+        axon::serve::{check_serving_rate_limit, endpoint, serving_hotkey},
+        error::CommandError,
+        submit::preview,
+    },
+    config,
+    keystore::error::KeystoreError,
+    subtensor::{address::Address, block::At, interface::api, net::ip_to_int, queries},
+};
+
+#[derive(Debug, Parser)]
+pub struct ServePrometheusArgs {
+    #[arg(long = "netuid", value_name = "NETUID", help = "Subnet to serve on")]
+    pub netuid: u16,
+
+    #[arg(
+        long = "ip",
+        value_name = "IP",
+        help = "IPv4 or IPv6 address of the prometheus endpoint"
+    )]
+    pub ip: IpAddr,
+
+    #[arg(
+        long = "port",
+        value_name = "PORT",
+        help = "Port of the prometheus endpoint"
+    )]
+    pub port: u16,
+
+    #[arg(
+        long = "version",
+        value_name = "INTEGER",
+        default_value_t = 0,
+        help = "Version announced with the endpoint"
+    )]
+    pub version: u32,
+
+    // coldkey
+    #[arg(
+        long = "wallet",
+        value_name = "STRING",
+        help = "Specifies the coldkey wallet the hotkey belongs to"
+    )]
+    pub wallet: Option<String>,
+
+    // hotkey name under the wallet or ss58 address
+    #[arg(
+        long = "hotkey",
+        value_name = "STRING",
+        help = "Name of a hotkey under the wallet or its SS58 address, serving the endpoint"
+    )]
+    pub hotkey: Option<Address>,
+}
+
+pub async fn serve_prometheus(
+    config: &config::Config,
+    args: &ServePrometheusArgs,
+) -> Result<(), CommandError> {
+    let (client, signer) = serving_hotkey(config, &args.wallet, &args.hotkey, args.netuid).await?;
+    let hotkey = signer.public_key().to_account_id();
+
+    let last_served = queries::prometheus(&client, At::Latest, args.netuid, &hotkey)
+        .await?
+        .map(|prometheus| prometheus.block)
+        .unwrap_or_default();
+    check_serving_rate_limit(&client, args.netuid, last_served).await?;
+
+    let (ip, ip_type) = ip_to_int(args.ip);
+
+    println!("Hotkey:     {hotkey}");
+    println!("Subnet:     {}", args.netuid);
+    println!("Prometheus: {}", endpoint(args.ip, args.port));
+    println!("Version:    {}", args.version);
+
+    let serve_prometheus_tx = api::tx().subtensor_module().serve_prometheus(
+        args.netuid,
+        args.version,
+        ip,
+        args.port,
+        ip_type,
+    );
+
+    if config.dry_run {
+        preview(&client, &serve_prometheus_tx, &signer).await?;
+        println!("Dry run, nothing submitted");
+        return Ok(());
+    }
+
+    let confirmed = Confirm::new()
+        .with_prompt("Announce this prometheus endpoint?")
+        .default(false)
+        .interact()
+        .map_err(KeystoreError::DialogueError)?;
+
+    if !confirmed {
+        println!("Serving cancelled");
+        return Ok(());
+    }
+
+    let events = client
+        .tx()
+        .sign_and_submit_then_watch_default(&serve_prometheus_tx, &signer)
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    if let Some(event) = events.find_first::<api::subtensor_module::events::PrometheusServed>()? {
+        println!("Prometheus served for {} on subnet {}", event.1, event.0);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use clap::Parser;
+use dialoguer::{Confirm, Input};
+use sp_core::crypto::Ss58AddressFormat;
+
+use crate::{
+    commands::error::CommandError,
+    config,
+    keystore::{self, cli::KeystoreArgs, error::KeystoreError, Keystore},
+};
+
+#[derive(Debug, Parser)]
+pub struct RegenColdkeyArgs {
+    #[arg(
+        long = "mnemonic",
+        value_name = "WORDS",
+        conflicts_with = "seed",
+        help = "Mnemonic to regenerate the coldkey from, words separated by spaces"
+    )]
+    pub mnemonic: Option<String>,
+
+    #[arg(
+        long = "seed",
+        value_name = "HEX",
+        conflicts_with = "mnemonic",
+        help = "Hex encoded 32 byte secretSeed to regenerate the coldkey from"
+    )]
+    pub seed: Option<String>,
+
+    #[command(flatten)]
+    pub keystore_params: KeystoreArgs,
+}
+
+pub fn regen_coldkey(config: &config::Config, args: &RegenColdkeyArgs) -> Result<(), CommandError> {
+    let (mnemonic, seed) = match (&args.mnemonic, &args.seed) {
+        (None, None) => {
+            let secret = Input::<String>::new()
+                .with_prompt("Enter the mnemonic or the 0x prefixed seed")
+                .interact()
+                .map_err(KeystoreError::DialogueError)?;
+
+            if secret.trim().starts_with("0x") {
+                (None, Some(secret))
+            } else {
+                (Some(secret), None)
+            }
+        }
+        (mnemonic, seed) => (mnemonic.clone(), seed.clone()),
+    };
+
+    let name = args.keystore_params.read_name()?;
+    let password = args.keystore_params.read_password()?;
+
+    let keystore = match (mnemonic, seed) {
+        (Some(mnemonic), _) => {
+            let phrase = mnemonic.split_whitespace().collect::<Vec<_>>().join(" ");
+            keystore::validate_wordcount(Some(phrase.split(' ').count()))?;
+
+            Keystore::new::<sp_core::sr25519::Pair>(
+                &name,
+                &phrase,
+                password,
+                Some(Ss58AddressFormat::custom(42)),
+            )?
+        }
+        (None, Some(seed)) => Keystore::new_from_seed::<sp_core::sr25519::Pair>(
+            &name,
+            &seed,
+            password,
+            Some(Ss58AddressFormat::custom(42)),
+        )?,
+        (None, None) => return Err(CommandError::Input("No mnemonic or seed provided".into())),
+    };
+
+    let full_path = config.key_path.join(&name);
+    if full_path.join("coldkey").exists() {
+        let overwrite = Confirm::new()
+            .with_prompt(format!("Coldkey {name} already exists, overwrite it?"))
+            .default(false)
+            .interact()
+            .map_err(KeystoreError::DialogueError)?;
+
+        if !overwrite {
+            return Ok(());
+        }
+    }
+
+    keystore::create_keyfile_directory(&full_path)?;
+
+    println!("Regenerated coldkey: {}", keystore.ss58_address());
+    println!("Saving keystore to: {:?}", full_path);
+
+    keystore.save_unencrypted_without_secrets_to_file(&full_path)?;
+    keystore.save_encrypted_with_secrets_to_file(&full_path)?;
+
+    Ok(())
+}
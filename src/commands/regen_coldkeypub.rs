@@ -0,0 +1,90 @@
+use clap::Parser;
+use dialoguer::Confirm;
+use sp_core::{
+    crypto::{ByteArray, Ss58AddressFormat, Ss58Codec},
+    sr25519,
+};
+
+use crate::{
+    commands::error::CommandError,
+    config,
+    keystore::{self, cli::KeystoreArgs, error::KeystoreError, Keystore},
+};
+
+#[derive(Debug, Parser)]
+pub struct RegenColdkeypubArgs {
+    #[arg(
+        long = "ss58-address",
+        value_name = "SS58",
+        conflicts_with = "public_key",
+        required_unless_present = "public_key",
+        help = "SS58 address of the coldkey"
+    )]
+    pub ss58_address: Option<String>,
+
+    #[arg(
+        long = "public-key",
+        value_name = "HEX",
+        conflicts_with = "ss58_address",
+        required_unless_present = "ss58_address",
+        help = "Hex encoded public key of the coldkey"
+    )]
+    pub public_key: Option<String>,
+
+    #[command(flatten)]
+    pub keystore_params: KeystoreArgs,
+}
+
+pub fn regen_coldkeypub(
+    config: &config::Config,
+    args: &RegenColdkeypubArgs,
+) -> Result<(), CommandError> {
+    let public_key = match (&args.ss58_address, &args.public_key) {
+        (Some(address), _) => sr25519::Public::from_ss58check(address.trim())
+            .map_err(|e| CommandError::Input(format!("Invalid ss58 address: {e}")))?,
+        (None, Some(public_key)) => {
+            let bytes = hex::decode(public_key.trim().trim_start_matches("0x"))
+                .map_err(|e| CommandError::Input(format!("Invalid public key: {e}")))?;
+
+            sr25519::Public::from_slice(&bytes)
+                .map_err(|_| CommandError::Input("Public key must be 32 bytes".into()))?
+        }
+        (None, None) => {
+            return Err(CommandError::Input(
+                "No ss58 address or public key provided".into(),
+            ))
+        }
+    };
+
+    let name = args.keystore_params.read_name()?;
+
+    let keystore = Keystore::new_from_public::<sr25519::Pair>(
+        &name,
+        public_key,
+        Some(Ss58AddressFormat::custom(42)),
+    );
+
+    let full_path = config.key_path.join(&name);
+    if full_path.join("coldkeypub.txt").exists() {
+        let overwrite = Confirm::new()
+            .with_prompt(format!(
+                "Coldkeypub for {name} already exists, overwrite it?"
+            ))
+            .default(false)
+            .interact()
+            .map_err(KeystoreError::DialogueError)?;
+
+        if !overwrite {
+            return Ok(());
+        }
+    }
+
+    keystore::create_keyfile_directory(&full_path)?;
+
+    println!("Regenerated coldkeypub: {}", keystore.ss58_address());
+    println!("Saving keystore to: {:?}", full_path);
+
+    keystore.save_unencrypted_without_secrets_to_file(&full_path)?;
+
+    Ok(())
+}
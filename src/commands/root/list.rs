@@ -0,0 +1,36 @@
+use clap::Parser;
+use subxt::{OnlineClient, SubstrateConfig};
+
+use crate::{
+    commands::{block::BlockArgs, error::CommandError, table::render_table},
+    config,
+    subtensor::queries,
+};
+
+#[derive(Debug, Parser)]
+pub struct RootListArgs {
+    #[command(flatten)]
+    pub block: BlockArgs,
+}
+
+pub async fn root_list(config: &config::Config, args: &RootListArgs) -> Result<(), CommandError> {
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+    let at = args.block.at(config, &client).await?;
+
+    let n = queries::subnetwork_n(&client, at, 0).await?;
+
+    let mut rows = Vec::new();
+    for uid in 0..n {
+        let Some(hotkey) = queries::hotkey_of_uid(&client, at, 0, uid).await? else {
+            continue;
+        };
+        let stake = queries::total_hotkey_stake(&client, at, &hotkey).await?;
+
+        rows.push(vec![uid.to_string(), hotkey.to_string(), stake.to_string()]);
+    }
+
+    println!("Root network: {} members", rows.len());
+    print!("{}", render_table(&["UID", "HOTKEY", "STAKE"], &rows));
+
+    Ok(())
+}
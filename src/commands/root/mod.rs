@@ -0,0 +1,22 @@
+pub mod list;
+pub mod register;
+pub mod weights;
+
+use clap::Subcommand;
+
+use self::{list::RootListArgs, register::RootRegisterArgs, weights::RootWeightsArgs};
+
+/// commands for the root network, netuid 0
+#[derive(Debug, Subcommand)]
+pub enum RootCommands {
+    /// Register a hotkey on the root network
+    #[command(arg_required_else_help = true)]
+    Register(RootRegisterArgs),
+
+    /// Set the root weights of a hotkey across subnets
+    #[command(arg_required_else_help = true)]
+    Weights(RootWeightsArgs),
+
+    /// Show the members of the root network with their stake
+    List(RootListArgs),
+}
@@ -0,0 +1,110 @@
+use clap::Parser;
+use dialoguer::Confirm;
+use subxt::{OnlineClient, SubstrateConfig};
+
+use crate::{
+    commands::{error::CommandError, submit::preview},
+    config,
+    keystore::{
+        cli::{unlock_coldkey, PasswordArgs},
+        error::KeystoreError,
+    },
+    subtensor::{address::Address, block::At, interface::api, queries},
+};
+
+#[derive(Debug, Parser)]
+pub struct RootRegisterArgs {
+    // coldkey
+    #[arg(
+        long = "wallet",
+        value_name = "STRING",
+        help = "Specifies the coldkey wallet that owns the hotkey"
+    )]
+    pub wallet: Option<String>,
+
+    // hotkey name under the wallet or ss58 address
+    #[arg(
+        long = "hotkey",
+        value_name = "STRING",
+        help = "Name of a hotkey under the wallet or its SS58 address"
+    )]
+    pub hotkey: Option<Address>,
+
+    #[command(flatten)]
+    pub password_params: PasswordArgs,
+}
+
+pub async fn root_register(
+    config: &config::Config,
+    args: &RootRegisterArgs,
+) -> Result<(), CommandError> {
+    let coldkey = args
+        .wallet
+        .clone()
+        .or_else(|| config.default_coldkey.clone())
+        .ok_or_else(|| CommandError::Input("No coldkey specified".into()))?;
+    let hotkey = args
+        .hotkey
+        .clone()
+        .or_else(|| config.default_hotkey.clone().map(Address::Name))
+        .ok_or_else(|| CommandError::Input("No hotkey specified".into()))?;
+    let hotkey = hotkey.hotkey_account_id(&config.key_path, &coldkey)?;
+
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+
+    if let Some(uid) = queries::uid(&client, At::Latest, 0, &hotkey).await? {
+        println!("Hotkey {hotkey} is already on the root network with uid {uid}");
+        return Ok(());
+    }
+
+    let keystore = unlock_coldkey(
+        &config.key_path.join(&coldkey),
+        args.password_params.password.clone(),
+    )?;
+    let signer = keystore.to_keypair()?;
+
+    println!(
+        "Coldkey: {coldkey} ({})",
+        signer.public_key().to_account_id()
+    );
+    println!("Hotkey:  {hotkey}");
+    println!(
+        "Stake:   {}",
+        queries::total_hotkey_stake(&client, At::Latest, &hotkey).await?
+    );
+
+    let root_register_tx = api::tx().subtensor_module().root_register(hotkey);
+
+    if config.dry_run {
+        preview(&client, &root_register_tx, &signer).await?;
+        println!("Dry run, nothing submitted");
+        return Ok(());
+    }
+
+    let confirmed = Confirm::new()
+        .with_prompt("Register this hotkey on the root network?")
+        .default(false)
+        .interact()
+        .map_err(KeystoreError::DialogueError)?;
+
+    if !confirmed {
+        println!("Registration cancelled");
+        return Ok(());
+    }
+
+    let events = client
+        .tx()
+        .sign_and_submit_then_watch_default(&root_register_tx, &signer)
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    if let Some(event) = events.find_first::<api::subtensor_module::events::NeuronRegistered>()? {
+        println!(
+            "Registered {} on the root network with uid {}",
+            event.2, event.1
+        );
+    }
+
+    Ok(())
+}
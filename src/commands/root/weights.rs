@@ -0,0 +1,197 @@
+use clap::Parser;
+use dialoguer::Confirm;
+use subxt::{OnlineClient, SubstrateConfig};
+
+use crate::{
+    commands::{error::CommandError, submit::preview, table::render_table},
+    config,
+    keystore::error::KeystoreError,
+    subtensor::{
+        address::Address,
+        block::At,
+        interface::api,
+        queries,
+        weights::{find_duplicate, normalize_weights},
+    },
+};
+
+#[derive(Debug, Parser)]
+pub struct RootWeightsArgs {
+    #[arg(
+        long = "netuids",
+        value_name = "NETUIDS",
+        value_delimiter = ',',
+        required = true,
+        help = "Comma separated subnets to weight"
+    )]
+    pub netuids: Vec<u16>,
+
+    #[arg(
+        long = "weights",
+        value_name = "WEIGHTS",
+        value_delimiter = ',',
+        required = true,
+        help = "Comma separated weights, one per netuid"
+    )]
+    pub weights: Vec<f64>,
+
+    // coldkey
+    #[arg(
+        long = "wallet",
+        value_name = "STRING",
+        help = "Specifies the coldkey wallet the hotkey belongs to"
+    )]
+    pub wallet: Option<String>,
+
+    // hotkey name under the wallet or ss58 address
+    #[arg(
+        long = "hotkey",
+        value_name = "STRING",
+        help = "Name of a root hotkey under the wallet or its SS58 address, setting the weights"
+    )]
+    pub hotkey: Option<Address>,
+}
+
+pub async fn root_weights(
+    config: &config::Config,
+    args: &RootWeightsArgs,
+) -> Result<(), CommandError> {
+    if args.netuids.len() != args.weights.len() {
+        return Err(CommandError::Input(format!(
+            "Got {} netuids but {} weights",
+            args.netuids.len(),
+            args.weights.len()
+        )));
+    }
+    if let Some(netuid) = find_duplicate(&args.netuids) {
+        return Err(CommandError::Input(format!(
+            "Netuid {netuid} is given more than once"
+        )));
+    }
+    let weights = normalize_weights(&args.weights).map_err(CommandError::Input)?;
+
+    let wallet_name = args
+        .wallet
+        .clone()
+        .or_else(|| config.default_coldkey.clone())
+        .ok_or_else(|| CommandError::Input("No wallet specified".into()))?;
+    let hotkey_name = args
+        .hotkey
+        .clone()
+        .or_else(|| config.default_hotkey.clone().map(Address::Name))
+        .ok_or_else(|| CommandError::Input("No hotkey specified".into()))?;
+
+    // weights are set by the hotkey itself
+    let signer = hotkey_name
+        .hotkey_keystore(&config.key_path, &wallet_name)?
+        .to_keypair()?;
+    let hotkey = signer.public_key().to_account_id();
+
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+
+    let subnets = queries::subnets(&client, At::Latest).await?;
+    if let Some(netuid) = args.netuids.iter().find(|n| !subnets.contains(n)) {
+        return Err(CommandError::Input(format!(
+            "Subnet {netuid} does not exist"
+        )));
+    }
+
+    let uid = queries::uid(&client, At::Latest, 0, &hotkey)
+        .await?
+        .ok_or_else(|| {
+            CommandError::Input(format!(
+                "Hotkey {hotkey_name} is not registered on the root network"
+            ))
+        })?;
+    let version_key = queries::weights_version_key(&client, At::Latest, 0).await?;
+
+    println!("Hotkey: {hotkey_name} ({hotkey}), root uid {uid}");
+    let rows: Vec<_> = args
+        .netuids
+        .iter()
+        .zip(&args.weights)
+        .zip(&weights)
+        .map(|((netuid, weight), normalized)| {
+            vec![
+                netuid.to_string(),
+                weight.to_string(),
+                normalized.to_string(),
+            ]
+        })
+        .collect();
+    print!("{}", render_table(&["NETUID", "WEIGHT", "U16"], &rows));
+
+    let set_weights_tx =
+        api::tx()
+            .subtensor_module()
+            .set_weights(0, args.netuids.clone(), weights, version_key);
+
+    if config.dry_run {
+        preview(&client, &set_weights_tx, &signer).await?;
+        println!("Dry run, nothing submitted");
+        return Ok(());
+    }
+
+    let confirmed = Confirm::new()
+        .with_prompt("Set these root weights?")
+        .default(false)
+        .interact()
+        .map_err(KeystoreError::DialogueError)?;
+
+    if !confirmed {
+        println!("Setting weights cancelled");
+        return Ok(());
+    }
+
+    let events = client
+        .tx()
+        .sign_and_submit_then_watch_default(&set_weights_tx, &signer)
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    if let Some(event) = events.find_first::<api::subtensor_module::events::WeightsSet>()? {
+        println!("Root weights set for uid {} on netuid {}", event.1, event.0);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_root_weights_args() {
+        let args = RootWeightsArgs::try_parse_from([
+            "weights",
+            "--netuids",
+            "1,3",
+            "--weights",
+            "0.7,0.3",
+        ])
+        .unwrap();
+
+        assert_eq!(args.netuids, vec![1, 3]);
+        assert_eq!(args.weights, vec![0.7, 0.3]);
+        assert!(RootWeightsArgs::try_parse_from(["weights", "--netuids", "1"]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_root_weights_rejects_duplicate_netuids() {
+        let args = RootWeightsArgs::try_parse_from([
+            "weights",
+            "--netuids",
+            "1,3,1",
+            "--weights",
+            "1,1,1",
+        ])
+        .unwrap();
+
+        // rejected before any key is read or the chain is contacted
+        let result = root_weights(&config::Config::default(), &args).await;
+        assert!(
+            matches!(result, Err(CommandError::Input(message)) if message.contains("Netuid 1"))
+        );
+    }
+}
@@ -1 +1,320 @@
-//
+use clap::{Parser, Subcommand};
+use dialoguer::Confirm;
+use subxt::{utils::AccountId32, OnlineClient, SubstrateConfig};
+
+use crate::{
+    commands::{
+        error::CommandError,
+        submit::{preview, tx_fee},
+    },
+    config,
+    keystore::{
+        cli::{unlock_coldkey, PasswordArgs},
+        error::KeystoreError,
+    },
+    subtensor::{address::Address, block::At, interface::api, queries, units::Balance},
+};
+
+/// commands moving TAO between a coldkey and the stake it holds on a hotkey
+#[derive(Debug, Subcommand)]
+pub enum StakeCommands {
+    /// Stake TAO from the coldkey onto a hotkey
+    #[command(arg_required_else_help = true)]
+    Add(StakeArgs),
+
+    /// Unstake TAO from a hotkey back to the coldkey
+    #[command(arg_required_else_help = true)]
+    Remove(StakeArgs),
+}
+
+#[derive(Debug, Parser)]
+pub struct StakeArgs {
+    // coldkey
+    #[arg(
+        long = "wallet",
+        value_name = "STRING",
+        help = "Specifies the coldkey wallet to stake from"
+    )]
+    pub wallet: Option<String>,
+
+    // hotkey name under the wallet or ss58 address
+    #[arg(
+        long = "hotkey",
+        value_name = "STRING",
+        help = "Name of a hotkey under the wallet or its SS58 address"
+    )]
+    pub hotkey: Option<Address>,
+
+    // Amount, given on the command line in TAO or with a rao suffix
+    #[arg(
+        long = "amount",
+        value_name = "TAO",
+        conflicts_with = "all",
+        required_unless_present = "all"
+    )]
+    pub amount: Option<Balance>,
+
+    #[arg(long = "all", help = "Use the whole available amount")]
+    pub all: bool,
+
+    #[command(flatten)]
+    pub password_params: PasswordArgs,
+}
+
+pub async fn add_stake(config: &config::Config, args: &StakeArgs) -> Result<(), CommandError> {
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+    add_stake_with_client(config, &client, args).await
+}
+
+/// `add_stake` on an already connected client
+pub async fn add_stake_with_client(
+    config: &config::Config,
+    client: &OnlineClient<SubstrateConfig>,
+    args: &StakeArgs,
+) -> Result<(), CommandError> {
+    let (coldkey, hotkey) = resolve_keys(config, args)?;
+
+    let keystore = unlock_coldkey(
+        &config.key_path.join(&coldkey),
+        args.password_params.password.clone(),
+    )?;
+    let signer = keystore.to_keypair()?;
+    let account_id = signer.public_key().to_account_id();
+
+    let balance = queries::free_balance(client, At::Latest, &account_id).await?;
+    let amount = match args.amount {
+        Some(amount) => amount,
+        None => {
+            // the fee does not depend on the amount, so estimate it for the whole balance
+            let tx = api::tx()
+                .subtensor_module()
+                .add_stake(hotkey.clone(), balance.rao());
+            let fee = tx_fee(client, &tx, &signer).await?;
+
+            stake_all_amount(balance, queries::existential_deposit(client).await?, fee)?
+        }
+    };
+    check_amount(amount, balance, "balance")?;
+
+    let staked = queries::stake(client, At::Latest, &hotkey, &account_id).await?;
+
+    println!("Coldkey: {coldkey} ({account_id})");
+    println!("Hotkey:  {hotkey}");
+    println!("Amount:  {amount}");
+    println!("Balance: {balance} -> {}", balance.saturating_sub(amount));
+    println!("Stake:   {staked} -> {}", staked.saturating_add(amount));
+
+    let add_stake_tx = api::tx()
+        .subtensor_module()
+        .add_stake(hotkey.clone(), amount.rao());
+
+    if config.dry_run {
+        preview(client, &add_stake_tx, &signer).await?;
+        println!("Dry run, nothing submitted");
+        return Ok(());
+    }
+
+    let confirmed = Confirm::new()
+        .with_prompt("Do you want to add this stake?")
+        .default(false)
+        .interact()
+        .map_err(KeystoreError::DialogueError)?;
+
+    if !confirmed {
+        println!("Staking cancelled");
+        return Ok(());
+    }
+
+    let events = client
+        .tx()
+        .sign_and_submit_then_watch_default(&add_stake_tx, &signer)
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    if let Some(event) = events.find_first::<api::subtensor_module::events::StakeAdded>()? {
+        println!("Stake added: {} to {}", Balance::from_rao(event.1), event.0);
+    }
+
+    let staked = queries::stake(client, At::Latest, &hotkey, &account_id).await?;
+    println!("New stake: {staked}");
+
+    Ok(())
+}
+
+pub async fn remove_stake(config: &config::Config, args: &StakeArgs) -> Result<(), CommandError> {
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+    remove_stake_with_client(config, &client, args).await
+}
+
+/// `remove_stake` on an already connected client
+pub async fn remove_stake_with_client(
+    config: &config::Config,
+    client: &OnlineClient<SubstrateConfig>,
+    args: &StakeArgs,
+) -> Result<(), CommandError> {
+    let (coldkey, hotkey) = resolve_keys(config, args)?;
+
+    let keystore = unlock_coldkey(
+        &config.key_path.join(&coldkey),
+        args.password_params.password.clone(),
+    )?;
+    let signer = keystore.to_keypair()?;
+    let account_id = signer.public_key().to_account_id();
+
+    let staked = queries::stake(client, At::Latest, &hotkey, &account_id).await?;
+    let amount = args.amount.unwrap_or(staked);
+    check_amount(amount, staked, "stake")?;
+
+    let balance = queries::free_balance(client, At::Latest, &account_id).await?;
+
+    println!("Coldkey: {coldkey} ({account_id})");
+    println!("Hotkey:  {hotkey}");
+    println!("Amount:  {amount}");
+    println!("Stake:   {staked} -> {}", staked.saturating_sub(amount));
+    println!("Balance: {balance} -> {}", balance.saturating_add(amount));
+
+    let remove_stake_tx = api::tx()
+        .subtensor_module()
+        .remove_stake(hotkey.clone(), amount.rao());
+
+    if config.dry_run {
+        preview(client, &remove_stake_tx, &signer).await?;
+        println!("Dry run, nothing submitted");
+        return Ok(());
+    }
+
+    let confirmed = Confirm::new()
+        .with_prompt("Do you want to remove this stake?")
+        .default(false)
+        .interact()
+        .map_err(KeystoreError::DialogueError)?;
+
+    if !confirmed {
+        println!("Unstaking cancelled");
+        return Ok(());
+    }
+
+    let events = client
+        .tx()
+        .sign_and_submit_then_watch_default(&remove_stake_tx, &signer)
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    if let Some(event) = events.find_first::<api::subtensor_module::events::StakeRemoved>()? {
+        println!(
+            "Stake removed: {} from {}",
+            Balance::from_rao(event.1),
+            event.0
+        );
+    }
+
+    let balance = queries::free_balance(client, At::Latest, &account_id).await?;
+    println!("New balance: {balance}");
+
+    Ok(())
+}
+
+/// coldkey wallet name and hotkey account, falling back to the configured defaults
+fn resolve_keys(
+    config: &config::Config,
+    args: &StakeArgs,
+) -> Result<(String, AccountId32), CommandError> {
+    let coldkey = args
+        .wallet
+        .clone()
+        .or_else(|| config.default_coldkey.clone())
+        .ok_or_else(|| CommandError::Input("No coldkey specified".into()))?;
+
+    let hotkey = args
+        .hotkey
+        .clone()
+        .or_else(|| config.default_hotkey.clone().map(Address::Name))
+        .ok_or_else(|| CommandError::Input("No hotkey specified".into()))?;
+
+    let hotkey = hotkey.hotkey_account_id(&config.key_path, &coldkey)?;
+
+    Ok((coldkey, hotkey))
+}
+
+/// what `--all` stakes, the balance minus the fee and the existential deposit that keeps the
+/// coldkey from being reaped
+fn stake_all_amount(
+    balance: Balance,
+    existential_deposit: Balance,
+    fee: Balance,
+) -> Result<Balance, CommandError> {
+    balance
+        .checked_sub(existential_deposit)
+        .and_then(|rest| rest.checked_sub(fee))
+        .ok_or_else(|| {
+            CommandError::Input(format!(
+                "Balance {balance} does not cover the existential deposit of {existential_deposit} \
+                 and the fee of {fee}"
+            ))
+        })
+}
+
+fn check_amount(amount: Balance, available: Balance, what: &str) -> Result<(), CommandError> {
+    if amount.is_zero() {
+        return Err(CommandError::Input(
+            "Amount must be greater than zero".into(),
+        ));
+    }
+    if amount > available {
+        return Err(CommandError::Input(format!(
+            "Insufficient {what}: {available} available, {amount} requested"
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stake_args() {
+        let args =
+            StakeArgs::try_parse_from(["add", "--hotkey", "miner1", "--amount", "2"]).unwrap();
+        assert_eq!(args.amount, Balance::from_tao(2));
+        assert_eq!(args.hotkey, Some(Address::Name("miner1".into())));
+        assert!(!args.all);
+
+        let args = StakeArgs::try_parse_from(["add", "--all"]).unwrap();
+        assert!(args.amount.is_none());
+        assert!(args.all);
+
+        assert!(StakeArgs::try_parse_from(["add", "--hotkey", "miner1"]).is_err());
+        assert!(StakeArgs::try_parse_from(["add", "--amount", "1", "--all"]).is_err());
+        assert!(StakeArgs::try_parse_from(["add", "--hotkey", "../miner1", "--all"]).is_err());
+    }
+
+    #[test]
+    fn test_stake_all_amount() {
+        let rao = Balance::from_rao;
+
+        assert_eq!(
+            stake_all_amount(rao(1_000), rao(500), rao(125)).unwrap(),
+            rao(375)
+        );
+        assert_eq!(
+            stake_all_amount(rao(625), rao(500), rao(125)).unwrap(),
+            rao(0)
+        );
+        assert!(stake_all_amount(rao(600), rao(500), rao(125)).is_err());
+        assert!(stake_all_amount(rao(400), rao(500), rao(0)).is_err());
+    }
+
+    #[test]
+    fn test_check_amount() {
+        let rao = Balance::from_rao;
+
+        assert!(check_amount(rao(5), rao(10), "balance").is_ok());
+        assert!(check_amount(rao(10), rao(10), "balance").is_ok());
+        assert!(check_amount(rao(0), rao(10), "balance").is_err());
+        assert!(check_amount(rao(11), rao(10), "stake").is_err());
+    }
+}
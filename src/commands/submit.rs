@@ -0,0 +1,101 @@
+use subxt::{
+    ext::{
+        codec::{Compact, Encode},
+        scale_value::scale::decode_as_type,
+    },
+    tx::{SubmittableExtrinsic, TxPayload},
+    OnlineClient, SubstrateConfig,
+};
+use subxt_signer::sr25519::Keypair;
+
+use crate::{commands::error::CommandError, subtensor::units::Balance};
+
+/// signs `tx` without submitting it, prints the decoded call and returns its estimated fee
+pub async fn preview<Call: TxPayload>(
+    client: &OnlineClient<SubstrateConfig>,
+    tx: &Call,
+    signer: &Keypair,
+) -> Result<Balance, CommandError> {
+    println!("Call:    {}", decode_call(client, tx)?);
+
+    let fee = tx_fee(client, tx, signer).await?;
+    println!("Fee:     {fee} (estimated)");
+
+    Ok(fee)
+}
+
+/// signs `tx` without submitting it and returns its estimated fee
+pub async fn tx_fee<Call: TxPayload>(
+    client: &OnlineClient<SubstrateConfig>,
+    tx: &Call,
+    signer: &Keypair,
+) -> Result<Balance, CommandError> {
+    let extrinsic = client
+        .tx()
+        .create_signed(tx, signer, Default::default())
+        .await?;
+
+    estimate_fee(client, &extrinsic).await
+}
+
+/// weight's ref time and proof size, dispatch class and partial fee, subtensor balances are u64
+type RuntimeDispatchInfo = (Compact<u64>, Compact<u64>, u8, u64);
+
+/// partial fee the runtime would charge for the signed `extrinsic`
+pub async fn estimate_fee(
+    client: &OnlineClient<SubstrateConfig>,
+    extrinsic: &SubmittableExtrinsic<SubstrateConfig, OnlineClient<SubstrateConfig>>,
+) -> Result<Balance, CommandError> {
+    let mut params = extrinsic.encoded().to_vec();
+    (extrinsic.encoded().len() as u32).encode_to(&mut params);
+
+    let (_, _, _, fee) = client
+        .runtime_api()
+        .at_latest()
+        .await?
+        .call_raw::<RuntimeDispatchInfo>("TransactionPaymentApi_query_info", Some(&params))
+        .await?;
+
+    Ok(Balance::from_rao(fee))
+}
+
+/// the call data of `tx` decoded against the runtime metadata
+pub fn decode_call<Call: TxPayload>(
+    client: &OnlineClient<SubstrateConfig>,
+    tx: &Call,
+) -> Result<String, CommandError> {
+    let call_data = client.tx().call_data(tx)?;
+    let metadata = client.metadata();
+
+    let call = decode_as_type(
+        &mut &call_data[..],
+        metadata.outer_enums().call_enum_ty(),
+        metadata.types(),
+    )
+    .map_err(|e| CommandError::Input(format!("Could not decode call: {e}")))?;
+
+    Ok(call.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use subxt::ext::codec::Decode;
+
+    use super::*;
+
+    #[test]
+    fn test_decode_dispatch_info() {
+        // ref time 1000, proof size 2000, normal class, a fee of 125000 rao
+        let encoded = [
+            0xa1, 0x0f, 0x41, 0x1f, 0x00, 0x48, 0xe8, 0x01, 0, 0, 0, 0, 0,
+        ];
+
+        let input = &mut &encoded[..];
+        let (ref_time, proof_size, class, fee) = RuntimeDispatchInfo::decode(input).unwrap();
+        assert!(input.is_empty());
+        assert_eq!(
+            (ref_time.0, proof_size.0, class, fee),
+            (1000, 2000, 0, 125_000)
+        );
+    }
+}
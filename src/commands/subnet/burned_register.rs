@@ -0,0 +1,175 @@
+use clap::Parser;
+use dialoguer::Confirm;
+use subxt::{OnlineClient, SubstrateConfig};
+
+use crate::{
+    commands::{error::CommandError, submit::preview},
+    config,
+    keystore::{
+        cli::{unlock_coldkey, PasswordArgs},
+        error::KeystoreError,
+    },
+    subtensor::{address::Address, block::At, interface::api, queries, units::Balance},
+};
+
+#[derive(Debug, Parser)]
+pub struct BurnedRegisterArgs {
+    #[arg(long = "netuid", value_name = "NETUID", help = "Subnet to register on")]
+    pub netuid: u16,
+
+    // coldkey
+    #[arg(
+        long = "wallet",
+        value_name = "STRING",
+        help = "Specifies the coldkey wallet that pays the burn"
+    )]
+    pub wallet: Option<String>,
+
+    // hotkey name under the wallet or ss58 address
+    #[arg(
+        long = "hotkey",
+        value_name = "STRING",
+        help = "Name of a hotkey under the wallet or its SS58 address"
+    )]
+    pub hotkey: Option<Address>,
+
+    // maximum burn, given on the command line in TAO or with a rao suffix
+    #[arg(
+        long = "max-burn",
+        value_name = "TAO",
+        help = "Refuse to register if the burn is above this amount"
+    )]
+    pub max_burn: Option<Balance>,
+
+    #[command(flatten)]
+    pub password_params: PasswordArgs,
+}
+
+pub async fn burned_register(
+    config: &config::Config,
+    args: &BurnedRegisterArgs,
+) -> Result<(), CommandError> {
+    let coldkey = args
+        .wallet
+        .clone()
+        .or_else(|| config.default_coldkey.clone())
+        .ok_or_else(|| CommandError::Input("No coldkey specified".into()))?;
+    let hotkey = args
+        .hotkey
+        .clone()
+        .or_else(|| config.default_hotkey.clone().map(Address::Name))
+        .ok_or_else(|| CommandError::Input("No hotkey specified".into()))?;
+    let hotkey = hotkey.hotkey_account_id(&config.key_path, &coldkey)?;
+
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+
+    if !queries::subnets(&client, At::Latest)
+        .await?
+        .contains(&args.netuid)
+    {
+        return Err(CommandError::Input(format!(
+            "Subnet {} does not exist",
+            args.netuid
+        )));
+    }
+    if let Some(uid) = queries::uid(&client, At::Latest, args.netuid, &hotkey).await? {
+        println!(
+            "Hotkey {hotkey} is already registered on subnet {} with uid {uid}",
+            args.netuid
+        );
+        return Ok(());
+    }
+
+    let keystore = unlock_coldkey(
+        &config.key_path.join(&coldkey),
+        args.password_params.password.clone(),
+    )?;
+    let signer = keystore.to_keypair()?;
+    let account_id = signer.public_key().to_account_id();
+
+    let burn = queries::burn(&client, At::Latest, args.netuid).await?;
+    let balance = queries::free_balance(&client, At::Latest, &account_id).await?;
+
+    println!("Coldkey: {coldkey} ({account_id})");
+    println!("Hotkey:  {hotkey}");
+    println!("Subnet:  {}", args.netuid);
+    println!("Burn:    {burn}");
+    println!("Balance: {balance}");
+
+    // without an explicit limit, never pay more than the price that was confirmed
+    let max_burn = args.max_burn.unwrap_or(burn);
+    check_burn(burn, max_burn, balance)?;
+
+    let burned_register_tx = api::tx()
+        .subtensor_module()
+        .burned_register(args.netuid, hotkey);
+
+    if config.dry_run {
+        preview(&client, &burned_register_tx, &signer).await?;
+        println!("Dry run, nothing submitted");
+        return Ok(());
+    }
+
+    let confirmed = Confirm::new()
+        .with_prompt(format!("Burn {burn} to register?"))
+        .default(false)
+        .interact()
+        .map_err(KeystoreError::DialogueError)?;
+
+    if !confirmed {
+        println!("Registration cancelled");
+        return Ok(());
+    }
+
+    // the burn moves every block, check it again right before submitting
+    let burn = queries::burn(&client, At::Latest, args.netuid).await?;
+    check_burn(burn, max_burn, balance)?;
+
+    let events = client
+        .tx()
+        .sign_and_submit_then_watch_default(&burned_register_tx, &signer)
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    if let Some(event) = events.find_first::<api::subtensor_module::events::NeuronRegistered>()? {
+        println!(
+            "Registered {} on subnet {} with uid {}",
+            event.2, event.0, event.1
+        );
+    }
+
+    let balance = queries::free_balance(&client, At::Latest, &account_id).await?;
+    println!("New balance: {balance}");
+
+    Ok(())
+}
+
+fn check_burn(burn: Balance, max_burn: Balance, balance: Balance) -> Result<(), CommandError> {
+    if burn > max_burn {
+        return Err(CommandError::Input(format!(
+            "Burn {burn} is above the limit of {max_burn}"
+        )));
+    }
+    if burn > balance {
+        return Err(CommandError::Input(format!(
+            "Insufficient balance: {balance} available, burn is {burn}"
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_burn() {
+        let rao = Balance::from_rao;
+
+        assert!(check_burn(rao(100), rao(100), rao(100)).is_ok());
+        assert!(check_burn(rao(101), rao(100), rao(1_000)).is_err());
+        assert!(check_burn(rao(100), rao(1_000), rao(99)).is_err());
+    }
+}
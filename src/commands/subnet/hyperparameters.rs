@@ -0,0 +1,44 @@
+use clap::Parser;
+use subxt::{OnlineClient, SubstrateConfig};
+
+use crate::{
+    commands::{block::BlockArgs, error::CommandError, table::render_table},
+    config,
+    subtensor::queries,
+};
+
+#[derive(Debug, Parser)]
+pub struct HyperparametersArgs {
+    #[arg(long = "netuid", value_name = "NETUID", help = "Subnet to read")]
+    pub netuid: u16,
+
+    #[command(flatten)]
+    pub block: BlockArgs,
+}
+
+pub async fn hyperparameters(
+    config: &config::Config,
+    args: &HyperparametersArgs,
+) -> Result<(), CommandError> {
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+    let at = args.block.at(config, &client).await?;
+    let netuid = args.netuid;
+
+    if !queries::subnets(&client, at).await?.contains(&netuid) {
+        return Err(CommandError::Input(format!(
+            "Subnet {netuid} does not exist"
+        )));
+    }
+
+    let rows: Vec<_> = queries::hyperparameters(&client, at, netuid)
+        .await?
+        .rows()
+        .into_iter()
+        .map(|(name, value)| vec![name.to_string(), value])
+        .collect();
+
+    println!("Hyperparameters of subnet {netuid}");
+    print!("{}", render_table(&["PARAMETER", "VALUE"], &rows));
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use clap::Parser;
+use subxt::{OnlineClient, SubstrateConfig};
+
+use crate::{
+    commands::{block::BlockArgs, error::CommandError, table::render_table},
+    config,
+    subtensor::{queries, subnet::SubnetInfo},
+};
+
+#[derive(Debug, Parser)]
+pub struct SubnetListArgs {
+    #[command(flatten)]
+    pub block: BlockArgs,
+}
+
+pub async fn subnet_list(
+    config: &config::Config,
+    args: &SubnetListArgs,
+) -> Result<(), CommandError> {
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+    let at = args.block.at(config, &client).await?;
+
+    let subnets = queries::subnet_infos(&client, at).await?;
+
+    println!("{} subnets", subnets.len());
+    print!("{}", render(&subnets));
+
+    Ok(())
+}
+
+fn render(subnets: &[SubnetInfo]) -> String {
+    let rows: Vec<_> = subnets
+        .iter()
+        .map(|subnet| {
+            vec![
+                subnet.netuid.to_string(),
+                format!("{}/{}", subnet.n, subnet.max_allowed_uids),
+                subnet.tempo.to_string(),
+                subnet.emission.to_string(),
+                subnet.burn.to_string(),
+                subnet.difficulty.to_string(),
+                subnet
+                    .owner
+                    .as_ref()
+                    .map_or_else(|| "-".to_string(), ToString::to_string),
+                subnet.registered_at.to_string(),
+            ]
+        })
+        .collect();
+
+    render_table(
+        &[
+            "NETUID",
+            "NEURONS",
+            "TEMPO",
+            "EMISSION",
+            "BURN",
+            "DIFFICULTY",
+            "OWNER",
+            "REGISTERED AT",
+        ],
+        &rows,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use subxt::utils::AccountId32;
+
+    use super::*;
+    use crate::subtensor::units::Balance;
+
+    #[test]
+    fn test_render() {
+        let subnets = [
+            SubnetInfo {
+                netuid: 0,
+                n: 12,
+                max_allowed_uids: 64,
+                tempo: 100,
+                emission: Balance::ZERO,
+                burn: Balance::ZERO,
+                difficulty: 10_000_000,
+                owner: None,
+                registered_at: 0,
+            },
+            SubnetInfo {
+                netuid: 3,
+                n: 256,
+                max_allowed_uids: 256,
+                tempo: 360,
+                emission: Balance::from_rao(1_000),
+                burn: Balance::from_tao(1).unwrap(),
+                difficulty: 1,
+                owner: Some(AccountId32([1; 32])),
+                registered_at: 42,
+            },
+        ];
+
+        let rendered = render(&subnets);
+        let lines: Vec<_> = rendered.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("NETUID  NEURONS"));
+        assert!(lines[1].starts_with("0       12/64"));
+        assert!(lines[1].contains(" - "));
+        assert!(lines[2].starts_with("3       256/256"));
+        assert!(lines[2].contains(&AccountId32([1; 32]).to_string()));
+        assert!(lines[2].ends_with("42"));
+    }
+}
@@ -0,0 +1,30 @@
+pub mod burned_register;
+pub mod hyperparameters;
+pub mod list;
+pub mod register;
+
+use clap::Subcommand;
+
+use self::{
+    burned_register::BurnedRegisterArgs, hyperparameters::HyperparametersArgs,
+    list::SubnetListArgs, register::RegisterArgs,
+};
+
+/// commands for joining and inspecting subnets
+#[derive(Debug, Subcommand)]
+pub enum SubnetCommands {
+    /// Register a hotkey on a subnet by solving the proof of work
+    #[command(arg_required_else_help = true)]
+    Register(RegisterArgs),
+
+    /// Register a hotkey on a subnet by burning TAO from the coldkey
+    #[command(arg_required_else_help = true)]
+    BurnedRegister(BurnedRegisterArgs),
+
+    /// List every subnet with its size, tempo, emission and registration cost
+    List(SubnetListArgs),
+
+    /// Show every per-subnet setting of a subnet
+    #[command(arg_required_else_help = true)]
+    Hyperparameters(HyperparametersArgs),
+}
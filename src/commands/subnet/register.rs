@@ -0,0 +1,214 @@
+use std::{
+    io::Write,
+    time::{Duration, Instant},
+};
+
+use clap::Parser;
+use dialoguer::Confirm;
+use subxt::{tx::Payload, utils::AccountId32, OnlineClient, SubstrateConfig};
+
+use crate::{
+    commands::{error::CommandError, submit::preview},
+    config,
+    keystore::{error::KeystoreError, wallet},
+    subtensor::{
+        address::Address,
+        block::At,
+        interface::api,
+        pow::{self, Solver},
+        queries,
+    },
+};
+
+/// how often the hash rate is refreshed and the chain is checked for a new block
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Parser)]
+pub struct RegisterArgs {
+    #[arg(long = "netuid", value_name = "NETUID", help = "Subnet to register on")]
+    pub netuid: u16,
+
+    // coldkey
+    #[arg(
+        long = "wallet",
+        value_name = "STRING",
+        help = "Specifies the coldkey wallet the hotkey belongs to"
+    )]
+    pub wallet: Option<String>,
+
+    // hotkey name under the wallet or ss58 address
+    #[arg(
+        long = "hotkey",
+        value_name = "STRING",
+        help = "Name of a hotkey under the wallet or its SS58 address, to register"
+    )]
+    pub hotkey: Option<Address>,
+
+    #[arg(
+        long = "threads",
+        value_name = "INTEGER",
+        help = "Number of solver threads, defaults to all CPU cores"
+    )]
+    pub threads: Option<usize>,
+}
+
+pub async fn register(config: &config::Config, args: &RegisterArgs) -> Result<(), CommandError> {
+    let wallet_name = args
+        .wallet
+        .clone()
+        .or_else(|| config.default_coldkey.clone())
+        .ok_or_else(|| CommandError::Input("No wallet specified".into()))?;
+    let hotkey_name = args
+        .hotkey
+        .clone()
+        .or_else(|| config.default_hotkey.clone().map(Address::Name))
+        .ok_or_else(|| CommandError::Input("No hotkey specified".into()))?;
+
+    // PoW registration is signed by the hotkey, the coldkey only needs its public half
+    let signer = hotkey_name
+        .hotkey_keystore(&config.key_path, &wallet_name)?
+        .to_keypair()?;
+    let hotkey = signer.public_key().to_account_id();
+    let coldkey = wallet::coldkey_account_id(&config.key_path, &wallet_name)?;
+
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+
+    if !queries::subnets(&client, At::Latest)
+        .await?
+        .contains(&args.netuid)
+    {
+        return Err(CommandError::Input(format!(
+            "Subnet {} does not exist",
+            args.netuid
+        )));
+    }
+    if let Some(uid) = queries::uid(&client, At::Latest, args.netuid, &hotkey).await? {
+        println!(
+            "Hotkey {hotkey_name} is already registered on subnet {} with uid {uid}",
+            args.netuid
+        );
+        return Ok(());
+    }
+
+    let difficulty = queries::difficulty(&client, At::Latest, args.netuid).await?;
+    let threads = args.threads.unwrap_or_else(|| {
+        std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+    });
+
+    println!("Hotkey:     {hotkey_name} ({hotkey})");
+    println!("Coldkey:    {wallet_name} ({coldkey})");
+    println!("Subnet:     {}", args.netuid);
+    println!("Difficulty: {difficulty}");
+
+    if config.dry_run {
+        // the fee does not depend on the seal, so estimate it without solving
+        let block_number = u64::from(client.blocks().at_latest().await?.number());
+        let solution = pow::Solution {
+            nonce: 0,
+            seal: [0; 32],
+        };
+        let register_tx = register_call(args.netuid, block_number, &solution, &hotkey, &coldkey);
+
+        preview(&client, &register_tx, &signer).await?;
+        println!("Dry run, nothing submitted");
+        return Ok(());
+    }
+
+    let confirmed = Confirm::new()
+        .with_prompt(format!("Solve the proof of work on {threads} threads?"))
+        .default(false)
+        .interact()
+        .map_err(KeystoreError::DialogueError)?;
+
+    if !confirmed {
+        println!("Registration cancelled");
+        return Ok(());
+    }
+
+    let (block_number, solution) = solve(&client, &hotkey, difficulty, threads).await?;
+    println!(
+        "Found seal 0x{} at block {block_number}",
+        hex::encode(solution.seal)
+    );
+
+    let register_tx = register_call(args.netuid, block_number, &solution, &hotkey, &coldkey);
+
+    let events = client
+        .tx()
+        .sign_and_submit_then_watch_default(&register_tx, &signer)
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    if let Some(event) = events.find_first::<api::subtensor_module::events::NeuronRegistered>()? {
+        println!(
+            "Registered {} on subnet {} with uid {}",
+            event.2, event.0, event.1
+        );
+    }
+
+    Ok(())
+}
+
+/// the `register` call submitting `solution`, found for the block `block_number`
+fn register_call(
+    netuid: u16,
+    block_number: u64,
+    solution: &pow::Solution,
+    hotkey: &AccountId32,
+    coldkey: &AccountId32,
+) -> Payload<api::subtensor_module::calls::types::Register> {
+    api::tx().subtensor_module().register(
+        netuid,
+        block_number,
+        solution.nonce,
+        solution.seal.to_vec(),
+        hotkey.clone(),
+        coldkey.clone(),
+    )
+}
+
+/// runs the solver against the latest block, restarting it whenever a newer block arrives
+async fn solve(
+    client: &OnlineClient<SubstrateConfig>,
+    hotkey: &AccountId32,
+    difficulty: u64,
+    threads: usize,
+) -> Result<(u64, pow::Solution), CommandError> {
+    let started = Instant::now();
+    let mut total_hashes = 0u64;
+
+    loop {
+        let block = client.blocks().at_latest().await?;
+        let block_number = u64::from(block.number());
+        let block_and_hotkey = pow::block_and_hotkey_hash(&block.hash().0, hotkey);
+
+        let solver = Solver::spawn(block_and_hotkey, difficulty, threads);
+
+        loop {
+            tokio::time::sleep(POLL_INTERVAL).await;
+
+            if let Some(solution) = solver.try_solution() {
+                println!();
+                return Ok((block_number, solution));
+            }
+
+            let hashes = total_hashes + solver.hashes();
+            let rate = hashes as f64 / started.elapsed().as_secs_f64();
+            print!(
+                "\rBlock {block_number}  {:>12.0} H/s  {hashes} hashes",
+                rate
+            );
+            let _ = std::io::stdout().flush();
+
+            // a seal is only accepted for a recent block, so start over on the new one
+            let latest = client.blocks().at_latest().await?;
+            if u64::from(latest.number()) > block_number {
+                total_hashes += solver.hashes();
+                break;
+            }
+        }
+    }
+}
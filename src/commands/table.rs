@@ -0,0 +1,44 @@
+/// renders rows as left aligned columns below a header line
+pub fn render_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let render_row = |cells: Vec<&str>| {
+        let line = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        format!("{}\n", line.trim_end())
+    };
+
+    let mut out = render_row(headers.to_vec());
+    for row in rows {
+        out.push_str(&render_row(row.iter().map(String::as_str).collect()));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_table() {
+        let rows = vec![
+            vec!["1".to_string(), "miner1".to_string()],
+            vec!["12".to_string(), "m".to_string()],
+        ];
+
+        assert_eq!(
+            render_table(&["NETUID", "HOTKEY"], &rows),
+            "NETUID  HOTKEY\n1       miner1\n12      m\n"
+        );
+    }
+}
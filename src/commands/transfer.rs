@@ -1,22 +1,24 @@
 use clap::Parser;
+use dialoguer::Confirm;
 use subxt::{OnlineClient, SubstrateConfig};
-use subxt_signer::{
-    bip39::Mnemonic,
-    sr25519::{dev, Keypair},
-};
 
-use crate::commands::error::CommandError;
+use crate::commands::{error::CommandError, submit::preview};
 use crate::config;
-use crate::subtensor::interface::api;
+use crate::keystore::{
+    cli::{unlock_coldkey, PasswordArgs},
+    error::KeystoreError,
+};
+use crate::subtensor::{address::Address, block::At, interface::api, queries, units::Balance};
 
 #[derive(Debug, Parser)]
 pub struct TransferArgs {
-    // Recipient address
+    // Recipient as SS58, hex public key or wallet name
     #[arg(long = "recipient", value_name = "Address of the recipient")]
-    pub recipient: String,
+    pub recipient: Address,
 
-    #[arg(long = "amount", value_name = "Amount to transfer")]
-    pub amount: u64,
+    // Amount, given on the command line in TAO or with a rao suffix
+    #[arg(long = "amount", value_name = "Amount to transfer in TAO")]
+    pub amount: Balance,
 
     // coldkey
     #[arg(
@@ -25,6 +27,9 @@ pub struct TransferArgs {
         help = "Specifies the coldkey to use"
     )]
     pub coldkey: Option<String>,
+
+    #[command(flatten)]
+    pub password_params: PasswordArgs,
 }
 
 impl TransferArgs {
@@ -36,70 +41,106 @@ impl TransferArgs {
 }
 
 pub async fn transfer(config: &config::Config, args: &TransferArgs) -> Result<(), CommandError> {
-    // extract args from config
-    // let from = if args.coldkey.is_none()
-
-    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint)
-        .await
-        .map_err(CommandError::Invalid)?;
-
-    let dest = dev::bob().public_key().into();
-
-    let balance_transfer_tx = api::tx().balances().transfer(dest, 10_000);
-
-    // test phrase
-    let phrase = "bottom drive obey lake curtain smoke basket hold race lonely fit walk";
-    let mnemonic = Mnemonic::parse(phrase).unwrap();
-    let from = Keypair::from_phrase(&mnemonic, None).unwrap();
-    let account_id = from.public_key().to_account_id().to_string();
-    let public_key = from.public_key().into();
-    println!("Account ID: {:?}", account_id);
-
-    let storage_query = api::storage().system().account(&public_key);
-
-    let result = client
-        .storage()
-        .at_latest()
-        .await
-        .map_err(CommandError::Invalid)?
-        .fetch(&storage_query)
-        .await;
-
-    println!("balance: {:?}", result);
-    match result {
-        Ok(data) => match data {
-            Some(data) => {
-                println!("Balance: {:?}", data.data.free);
-            }
-            None => {
-                println!("No data found");
-            }
-        },
-        Err(e) => {
-            println!("Error: {:?}", e);
-        }
+    let coldkey = args
+        .coldkey
+        .clone()
+        .or_else(|| config.default_coldkey.clone())
+        .ok_or_else(|| CommandError::Input("No coldkey specified".into()))?;
+
+    let dest = args.recipient.coldkey_account_id(&config.key_path)?;
+
+    if args.amount.is_zero() {
+        return Err(CommandError::Input(
+            "Amount must be greater than zero".into(),
+        ));
+    }
+
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+    println!(
+        "Connected to Substrate endpoint at {}",
+        &config.subtensor_endpoint
+    );
+
+    let keystore = unlock_coldkey(
+        &config.key_path.join(&coldkey),
+        args.password_params.password.clone(),
+    )?;
+    let from = keystore.to_keypair()?;
+    let account_id = from.public_key().to_account_id();
+
+    let balance = queries::free_balance(&client, At::Latest, &account_id).await?;
+    let remaining = balance.checked_sub(args.amount).ok_or_else(|| {
+        CommandError::Input(format!(
+            "Insufficient balance: {balance} available, {} requested",
+            args.amount
+        ))
+    })?;
+
+    println!("From:    {coldkey} ({account_id})");
+    println!("To:      {dest}");
+    println!("Amount:  {}", args.amount);
+    println!("Balance: {balance} -> {remaining}");
+
+    let balance_transfer_tx = api::tx()
+        .balances()
+        .transfer(dest.into(), args.amount.rao());
+
+    let fee = preview(&client, &balance_transfer_tx, &from).await?;
+    let existential_deposit = queries::existential_deposit(&client).await?;
+    check_transfer(balance, args.amount, fee, existential_deposit)?;
+
+    if config.dry_run {
+        println!("Dry run, nothing submitted");
+        return Ok(());
+    }
+
+    let confirmed = Confirm::new()
+        .with_prompt("Do you want to submit this transfer?")
+        .default(false)
+        .interact()
+        .map_err(KeystoreError::DialogueError)?;
+
+    if !confirmed {
+        println!("Transfer cancelled");
+        return Ok(());
     }
 
     let events = client
         .tx()
         .sign_and_submit_then_watch_default(&balance_transfer_tx, &from)
-        .await
-        .map_err(CommandError::Invalid)?
+        .await?
         .wait_for_finalized_success()
-        .await
-        .map_err(CommandError::Invalid)?;
+        .await?;
 
-    let transfer_event = events
-        .find_first::<api::balances::events::Transfer>()
-        .map_err(CommandError::Invalid)?;
+    let transfer_event = events.find_first::<api::balances::events::Transfer>()?;
     if let Some(event) = transfer_event {
         println!("Balance transfer success: {event:?}");
     }
 
-    println!(
-        "Connected to Substrate endpoint at {}",
-        &config.subtensor_endpoint
-    );
+    let balance = queries::free_balance(&client, At::Latest, &account_id).await?;
+    println!("New balance: {balance}");
+
+    Ok(())
+}
+
+/// the sender has to cover the amount and the fee and keep the existential deposit
+fn check_transfer(
+    balance: Balance,
+    amount: Balance,
+    fee: Balance,
+    existential_deposit: Balance,
+) -> Result<(), CommandError> {
+    let required = amount
+        .checked_add(fee)
+        .and_then(|total| total.checked_add(existential_deposit))
+        .ok_or_else(|| CommandError::Input(format!("Amount {amount} is too large")))?;
+
+    if balance < required {
+        return Err(CommandError::Input(format!(
+            "Insufficient balance: {balance} available, {required} needed for {amount} \
+             plus a {fee} fee and the {existential_deposit} existential deposit"
+        )));
+    }
 
     Ok(())
 }
@@ -109,5 +150,36 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_transfer_args() {}
+    fn test_transfer_args() {
+        let args = TransferArgs::try_parse_from([
+            "transfer",
+            "--recipient",
+            "5F71GxBcHF9UfE6uiDcFsgbPNmHJGwPtNfg3F87HEeVczotP",
+            "--amount",
+            "1.5",
+        ])
+        .unwrap();
+
+        assert_eq!(args.amount, Balance::from_rao(1_500_000_000));
+        assert!(args.coldkey.is_none());
+
+        assert!(TransferArgs::try_parse_from([
+            "transfer",
+            "--recipient",
+            "5F71GxBcHF9UfE6uiDcFsgbPNmHJGwPtNfg3F87HEeVczotP",
+            "--amount",
+            "1.0000000001",
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_check_transfer() {
+        let rao = Balance::from_rao;
+
+        assert!(check_transfer(rao(100), rao(90), rao(5), rao(5)).is_ok());
+        assert!(check_transfer(rao(100), rao(91), rao(5), rao(5)).is_err());
+        assert!(check_transfer(rao(100), rao(95), rao(5), rao(0)).is_ok());
+        assert!(check_transfer(rao(u64::MAX), rao(u64::MAX), rao(1), rao(0)).is_err());
+    }
 }
@@ -0,0 +1,211 @@
+use std::{fs, path::PathBuf};
+
+use clap::Parser;
+use subxt::{
+    config::{
+        ExtrinsicParams, ExtrinsicParamsEncoder, SubstrateExtrinsicParams,
+        SubstrateExtrinsicParamsBuilder,
+    },
+    dynamic::Value,
+    ext::scale_value::{
+        stringify::{custom_parsers, from_str_custom},
+        Composite, ValueDef,
+    },
+    OnlineClient, SubstrateConfig,
+};
+
+use crate::{
+    commands::{error::CommandError, submit::decode_call},
+    config,
+    keystore::error::KeystoreError,
+    subtensor::{
+        address::{encode_ss58, Address, SS58_PREFIX},
+        offline::{Era, UnsignedTx},
+    },
+};
+
+#[derive(Debug, Parser)]
+pub struct BuildTxArgs {
+    #[arg(
+        long = "pallet",
+        value_name = "PALLET",
+        help = "Pallet of the call, e.g. Balances or SubtensorModule"
+    )]
+    pub pallet: String,
+
+    #[arg(
+        long = "call",
+        value_name = "CALL",
+        help = "Name of the call, e.g. transfer or add_stake"
+    )]
+    pub call: String,
+
+    #[arg(
+        long = "args",
+        value_name = "VALUE",
+        default_value = "()",
+        help = "Call arguments, e.g. '{ hotkey: 5F..., amount_staked: 1000000000 }'"
+    )]
+    pub args: String,
+
+    // coldkey that will sign, or the wallet of the hotkey that will
+    #[arg(
+        long = "signer",
+        value_name = "ADDRESS",
+        help = "Coldkey wallet or SS58 address that will sign the transaction"
+    )]
+    pub signer: Option<Address>,
+
+    // hotkey name under the signer wallet or ss58 address, for calls the hotkey signs
+    #[arg(
+        long = "hotkey",
+        value_name = "ADDRESS",
+        help = "Hotkey of the --signer wallet or its SS58 address, signing instead of the coldkey"
+    )]
+    pub hotkey: Option<Address>,
+
+    #[arg(
+        long = "era-period",
+        value_name = "BLOCKS",
+        default_value_t = 256,
+        conflicts_with = "immortal",
+        help = "Number of blocks the transaction stays valid for"
+    )]
+    pub era_period: u64,
+
+    #[arg(long = "immortal", help = "Build a transaction that never expires")]
+    pub immortal: bool,
+
+    #[arg(
+        long = "output",
+        value_name = "FILE",
+        help = "File to write the unsigned transaction to"
+    )]
+    pub output: PathBuf,
+}
+
+pub async fn build_tx(config: &config::Config, args: &BuildTxArgs) -> Result<(), CommandError> {
+    let signer = args
+        .signer
+        .clone()
+        .or_else(|| config.default_coldkey.clone().map(Address::Name))
+        .ok_or_else(|| CommandError::Input("No signer specified".into()))?;
+    let signer = match &args.hotkey {
+        Some(Address::Account(hotkey)) => hotkey.clone(),
+        Some(hotkey) => match &signer {
+            Address::Name(wallet) => hotkey.hotkey_account_id(&config.key_path, wallet)?,
+            Address::Account(_) => {
+                return Err(CommandError::Input(
+                    "A hotkey name needs --signer to be the wallet it is stored under".into(),
+                ))
+            }
+        },
+        None => signer.coldkey_account_id(&config.key_path)?,
+    };
+
+    let fields = parse_call_args(&args.args).map_err(CommandError::Input)?;
+    let payload = subxt::dynamic::tx(&args.pallet, &args.call, fields);
+
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+
+    // encoding against the metadata rejects unknown calls and arguments of the wrong shape
+    let call_data = client.tx().call_data(&payload)?;
+    println!("Call:    {}", decode_call(&client, &payload)?);
+
+    let nonce = client.tx().account_nonce(&signer).await?;
+
+    let mut params = SubstrateExtrinsicParamsBuilder::new();
+    let era = if args.immortal {
+        None
+    } else {
+        let block = client.blocks().at_latest().await?;
+        params = params.mortal(block.header(), args.era_period);
+        Some(Era {
+            period: args.era_period,
+            block: block.number().into(),
+            block_hash: block.hash().0.to_vec(),
+        })
+    };
+
+    let extension_params = <SubstrateExtrinsicParams<SubstrateConfig> as ExtrinsicParams<
+        SubstrateConfig,
+    >>::new(nonce, client.clone(), params.build())
+    .map_err(subxt::Error::from)?;
+
+    let mut extra = Vec::new();
+    extension_params.encode_extra_to(&mut extra);
+    let mut additional = Vec::new();
+    extension_params.encode_additional_to(&mut additional);
+
+    let runtime_version = client.runtime_version();
+    let tx = UnsignedTx {
+        pallet: args.pallet.clone(),
+        call: args.call.clone(),
+        args: args.args.clone(),
+        signer: encode_ss58(&signer, SS58_PREFIX),
+        call_data,
+        nonce,
+        era,
+        genesis_hash: client.genesis_hash().0.to_vec(),
+        spec_version: runtime_version.spec_version,
+        transaction_version: runtime_version.transaction_version,
+        extra,
+        additional,
+    };
+    // refuse to write a file `tx sign` would reject
+    tx.check_extensions().map_err(CommandError::Input)?;
+    tx.decode_call().map_err(CommandError::Input)?;
+
+    println!("Signer:  {}", tx.signer);
+    println!("Nonce:   {nonce}");
+    match &tx.era {
+        Some(era) => println!("Era:     {} blocks from block {}", era.period, era.block),
+        None => println!("Era:     immortal"),
+    }
+
+    let json = serde_json::to_string_pretty(&tx).map_err(KeystoreError::JsonError)?;
+    fs::write(&args.output, json).map_err(KeystoreError::Io)?;
+    println!("Unsigned transaction written to {}", args.output.display());
+
+    Ok(())
+}
+
+/// parses call arguments written as a SCALE value, accepting SS58 addresses and hex bytes
+fn parse_call_args(s: &str) -> Result<Composite<()>, String> {
+    let (value, rest) = from_str_custom()
+        .add_custom_parser(custom_parsers::parse_ss58)
+        .add_custom_parser(custom_parsers::parse_hex)
+        .parse(s);
+    let value: Value = value.map_err(|e| format!("Invalid call arguments: {e}"))?;
+
+    if !rest.trim().is_empty() {
+        return Err(format!("Unexpected input after call arguments: {rest}"));
+    }
+
+    match value.value {
+        ValueDef::Composite(fields) => Ok(fields),
+        _ => Err("Call arguments must be a { named } or ( unnamed ) list".into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_call_args() {
+        let fields = parse_call_args(
+            "{ hotkey: 5F71GxBcHF9UfE6uiDcFsgbPNmHJGwPtNfg3F87HEeVczotP, amount_staked: 1000 }",
+        )
+        .unwrap();
+        assert!(matches!(&fields, Composite::Named(named) if named.len() == 2));
+
+        let fields = parse_call_args("(1, 0x0102)").unwrap();
+        assert!(matches!(&fields, Composite::Unnamed(unnamed) if unnamed.len() == 2));
+
+        assert!(matches!(parse_call_args("()").unwrap(), Composite::Unnamed(v) if v.is_empty()));
+        assert!(parse_call_args("1").is_err());
+        assert!(parse_call_args("(1) 2").is_err());
+        assert!(parse_call_args("{ amount: }").is_err());
+    }
+}
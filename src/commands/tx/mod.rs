@@ -0,0 +1,23 @@
+pub mod build;
+pub mod sign;
+pub mod submit;
+
+use clap::Subcommand;
+
+use self::{build::BuildTxArgs, sign::SignTxArgs, submit::SubmitTxArgs};
+
+/// commands for signing transactions on a machine without network access
+#[derive(Debug, Subcommand)]
+pub enum TxCommands {
+    /// Write an unsigned call with its nonce, era and chain details to a file
+    #[command(arg_required_else_help = true)]
+    Build(BuildTxArgs),
+
+    /// Sign a transaction file with a coldkey, without connecting to the chain
+    #[command(arg_required_else_help = true)]
+    Sign(SignTxArgs),
+
+    /// Broadcast a signed transaction file and wait for finalization
+    #[command(arg_required_else_help = true)]
+    Submit(SubmitTxArgs),
+}
@@ -0,0 +1,114 @@
+use std::{fs, path::PathBuf};
+
+use clap::Parser;
+use dialoguer::Confirm;
+
+use crate::{
+    commands::error::CommandError,
+    config,
+    keystore::{
+        cli::{unlock_coldkey, PasswordArgs},
+        error::KeystoreError,
+        wallet,
+    },
+    subtensor::offline::UnsignedTx,
+};
+
+#[derive(Debug, Parser)]
+pub struct SignTxArgs {
+    #[arg(
+        long = "file",
+        value_name = "FILE",
+        help = "Unsigned transaction written by tx build"
+    )]
+    pub file: PathBuf,
+
+    // coldkey
+    #[arg(
+        long = "wallet",
+        value_name = "STRING",
+        help = "Specifies the coldkey wallet to sign with"
+    )]
+    pub wallet: Option<String>,
+
+    // hotkey name under the wallet, for calls the hotkey signs
+    #[arg(
+        long = "hotkey",
+        value_name = "STRING",
+        help = "Sign with this hotkey of the wallet instead of the coldkey"
+    )]
+    pub hotkey: Option<String>,
+
+    #[arg(
+        long = "output",
+        value_name = "FILE",
+        help = "File to write the signed transaction to"
+    )]
+    pub output: PathBuf,
+
+    #[command(flatten)]
+    pub password_params: PasswordArgs,
+}
+
+/// signs a transaction file, never connecting to the chain
+pub fn sign_tx(config: &config::Config, args: &SignTxArgs) -> Result<(), CommandError> {
+    let wallet_name = args
+        .wallet
+        .clone()
+        .or_else(|| config.default_coldkey.clone())
+        .ok_or_else(|| CommandError::Input("No coldkey specified".into()))?;
+
+    let contents = fs::read_to_string(&args.file).map_err(KeystoreError::Io)?;
+    let tx: UnsignedTx = serde_json::from_str(&contents).map_err(KeystoreError::JsonError)?;
+    // the nonce, era and runtime shown below have to match the bytes that get signed
+    tx.check_extensions().map_err(CommandError::Input)?;
+
+    // decoded here rather than taken from the file, which the online machine wrote
+    let call = tx.decode_call().map_err(CommandError::Input)?;
+    println!("Call:         {call}");
+    println!("Call data:    0x{}", hex::encode(&tx.call_data));
+    println!("Signer:       {}", tx.signer);
+    println!("Nonce:        {}", tx.nonce);
+    match &tx.era {
+        Some(era) => println!(
+            "Era:          {} blocks from block {} (0x{})",
+            era.period,
+            era.block,
+            hex::encode(&era.block_hash)
+        ),
+        None => println!("Era:          immortal"),
+    }
+    println!("Genesis hash: 0x{}", hex::encode(&tx.genesis_hash));
+    println!(
+        "Runtime:      spec version {}, transaction version {}",
+        tx.spec_version, tx.transaction_version
+    );
+
+    let confirmed = Confirm::new()
+        .with_prompt("Sign this transaction?")
+        .default(false)
+        .interact()
+        .map_err(KeystoreError::DialogueError)?;
+
+    if !confirmed {
+        println!("Signing cancelled");
+        return Ok(());
+    }
+
+    let keypair = match &args.hotkey {
+        Some(hotkey) => wallet::read_hotkey(&config.key_path, &wallet_name, hotkey)?,
+        None => unlock_coldkey(
+            &config.key_path.join(&wallet_name),
+            args.password_params.password.clone(),
+        )?,
+    }
+    .to_keypair()?;
+
+    let signed = tx.sign(&keypair).map_err(CommandError::Input)?;
+
+    let json = serde_json::to_string_pretty(&signed).map_err(KeystoreError::JsonError)?;
+    fs::write(&args.output, json).map_err(KeystoreError::Io)?;
+    println!("Signed transaction written to {}", args.output.display());
+
+    Ok(())
+}
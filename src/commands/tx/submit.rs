@@ -0,0 +1,79 @@
+use std::{fs, path::PathBuf};
+
+use clap::Parser;
+use subxt::{tx::SubmittableExtrinsic, OnlineClient, SubstrateConfig};
+
+use crate::{
+    commands::{error::CommandError, submit::estimate_fee},
+    config,
+    keystore::error::KeystoreError,
+    subtensor::offline::SignedTx,
+};
+
+#[derive(Debug, Parser)]
+pub struct SubmitTxArgs {
+    #[arg(
+        long = "file",
+        value_name = "FILE",
+        help = "Signed transaction written by tx sign"
+    )]
+    pub file: PathBuf,
+}
+
+pub async fn submit_tx(config: &config::Config, args: &SubmitTxArgs) -> Result<(), CommandError> {
+    let contents = fs::read_to_string(&args.file).map_err(KeystoreError::Io)?;
+    let tx: SignedTx = serde_json::from_str(&contents).map_err(KeystoreError::JsonError)?;
+
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+
+    // a signature over another chain or runtime is only rejected once it reaches the pool
+    if client.genesis_hash().0[..] != tx.unsigned.genesis_hash[..] {
+        return Err(CommandError::Input(format!(
+            "Transaction was built for genesis 0x{}, the endpoint is on 0x{}",
+            hex::encode(&tx.unsigned.genesis_hash),
+            hex::encode(client.genesis_hash().0)
+        )));
+    }
+    let runtime_version = client.runtime_version();
+    if runtime_version.spec_version != tx.unsigned.spec_version
+        || runtime_version.transaction_version != tx.unsigned.transaction_version
+    {
+        return Err(CommandError::Input(format!(
+            "Transaction was built for spec version {} and transaction version {}, \
+             the chain runs {} and {}, build and sign it again",
+            tx.unsigned.spec_version,
+            tx.unsigned.transaction_version,
+            runtime_version.spec_version,
+            runtime_version.transaction_version
+        )));
+    }
+
+    let extrinsic = SubmittableExtrinsic::from_bytes(client.clone(), tx.extrinsic);
+
+    println!(
+        "Call:    {}.{} {}",
+        tx.unsigned.pallet, tx.unsigned.call, tx.unsigned.args
+    );
+    println!("Signer:  {}", tx.unsigned.signer);
+    println!("Hash:    {:?}", extrinsic.hash());
+    // the extrinsic is already signed, so a failed estimate should not keep it from the chain
+    match estimate_fee(&client, &extrinsic).await {
+        Ok(fee) => println!("Fee:     {fee} (estimated)"),
+        Err(e) => eprintln!("Warning: could not estimate the fee: {e}"),
+    }
+
+    if config.dry_run {
+        println!("Dry run, nothing submitted");
+        return Ok(());
+    }
+
+    let events = extrinsic
+        .submit_and_watch()
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    println!("Finalized in block {:?}", events.block_hash());
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use clap::Parser;
+use dialoguer::Confirm;
+
+use crate::{
+    commands::error::CommandError,
+    config,
+    keystore::{
+        self,
+        cli::{unlock_coldkey, KeystoreArgs},
+        error::KeystoreError,
+        EncryptionType, Keystore,
+    },
+};
+
+#[derive(Debug, Parser)]
+pub struct UpdateColdkeyArgs {
+    #[command(flatten)]
+    pub keystore_params: KeystoreArgs,
+}
+
+/// re-encrypts a legacy or ansible vault coldkey as NaCl
+pub fn update_coldkey(
+    config: &config::Config,
+    args: &UpdateColdkeyArgs,
+) -> Result<(), CommandError> {
+    let name = args.keystore_params.read_name()?;
+    let full_path = config.key_path.join(&name);
+
+    match keystore::coldkey_encryption_type(&full_path)? {
+        Some(EncryptionType::Nacl) => {
+            println!("Coldkey {name} is already NaCl encrypted");
+            return Ok(());
+        }
+        Some(encryption_type) => println!("Coldkey {name} is {encryption_type:?} encrypted"),
+        None => return Err(KeystoreError::UnknownFormat.into()),
+    }
+
+    let keystore = unlock_coldkey(&full_path, args.keystore_params.password.clone())?;
+
+    let confirmed = Confirm::new()
+        .with_prompt(format!(
+            "Re-encrypt coldkey {name} ({}) as NaCl?",
+            keystore.ss58_address()
+        ))
+        .default(true)
+        .interact()
+        .map_err(KeystoreError::DialogueError)?;
+
+    if !confirmed {
+        return Ok(());
+    }
+
+    keystore.save_encrypted_with_secrets_to_file(&full_path)?;
+
+    // make sure the new keyfile opens with the same password before reporting success
+    let reloaded = Keystore::new_from_disk(&full_path, keystore.password())?;
+    if reloaded.ss58_address() != keystore.ss58_address() {
+        return Err(CommandError::Input(
+            "Re-encrypted coldkey does not match the original".into(),
+        ));
+    }
+
+    println!("Coldkey {name} is now NaCl encrypted");
+
+    Ok(())
+}
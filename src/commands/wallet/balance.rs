@@ -0,0 +1,171 @@
+use std::str::FromStr;
+
+use clap::Parser;
+use subxt::{utils::AccountId32, OnlineClient, SubstrateConfig};
+
+use crate::{
+    commands::{block::BlockArgs, error::CommandError, table::render_table},
+    config,
+    keystore::wallet,
+    subtensor::{queries, units::Balance},
+};
+
+#[derive(Debug, Parser)]
+pub struct BalanceArgs {
+    #[arg(
+        long = "wallet",
+        value_name = "STRING",
+        conflicts_with = "all",
+        help = "Specifies the coldkey wallet to show the balance of"
+    )]
+    pub wallet: Option<String>,
+
+    #[arg(
+        long = "all",
+        help = "Show the balance of every wallet under the key path"
+    )]
+    pub all: bool,
+
+    #[command(flatten)]
+    pub block: BlockArgs,
+}
+
+pub async fn balance(config: &config::Config, args: &BalanceArgs) -> Result<(), CommandError> {
+    let wallets = if args.all {
+        wallet::list_wallets(&config.key_path)?
+    } else {
+        let name = args
+            .wallet
+            .clone()
+            .or_else(|| config.default_coldkey.clone())
+            .ok_or_else(|| CommandError::Input("No wallet specified".into()))?;
+
+        vec![wallet::read_wallet(&config.key_path, &name)?]
+    };
+
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+    let at = args.block.at(config, &client).await?;
+
+    let mut rows = Vec::new();
+    let mut total = Holdings::default();
+
+    for wallet in wallets {
+        let Some(coldkey_ss58) = wallet.coldkey_ss58 else {
+            eprintln!("Skipping {}, it has no coldkeypub.txt", wallet.name);
+            continue;
+        };
+        let coldkey = AccountId32::from_str(&coldkey_ss58)
+            .map_err(|e| CommandError::Input(format!("Invalid coldkey address: {e}")))?;
+
+        let account = queries::account_data(&client, at, &coldkey).await?;
+        let holdings = Holdings {
+            free: Balance::from_rao(account.free),
+            reserved: Balance::from_rao(account.reserved),
+            staked: queries::total_coldkey_stake(&client, at, &coldkey).await?,
+        };
+
+        total = total
+            .combine(&holdings)
+            .ok_or_else(|| CommandError::Input("Total balance overflows".into()))?;
+        rows.push(holdings.row(wallet.name, coldkey_ss58)?);
+    }
+
+    rows.push(total.row("Total".to_string(), String::new())?);
+
+    print!(
+        "{}",
+        render_table(
+            &["WALLET", "COLDKEY", "FREE", "RESERVED", "STAKED", "TOTAL"],
+            &rows
+        )
+    );
+
+    Ok(())
+}
+
+/// balances of one coldkey, or of several added up
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Holdings {
+    free: Balance,
+    reserved: Balance,
+    staked: Balance,
+}
+
+impl Holdings {
+    /// both added up, `None` on overflow
+    fn combine(&self, other: &Holdings) -> Option<Holdings> {
+        Some(Holdings {
+            free: self.free.checked_add(other.free)?,
+            reserved: self.reserved.checked_add(other.reserved)?,
+            staked: self.staked.checked_add(other.staked)?,
+        })
+    }
+
+    /// everything the coldkey owns, reserved funds included, `None` on overflow
+    fn total(&self) -> Option<Balance> {
+        Balance::checked_sum([self.free, self.reserved, self.staked])
+    }
+
+    fn row(&self, name: String, coldkey: String) -> Result<Vec<String>, CommandError> {
+        let total = self
+            .total()
+            .ok_or_else(|| CommandError::Input(format!("Balance of {name} overflows")))?;
+
+        Ok(vec![
+            name,
+            coldkey,
+            self.free.to_string(),
+            self.reserved.to_string(),
+            self.staked.to_string(),
+            total.to_string(),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_balance_args() {
+        let args = BalanceArgs::try_parse_from(["balance", "--all"]).unwrap();
+        assert!(args.all);
+        assert!(args.wallet.is_none());
+
+        assert!(BalanceArgs::try_parse_from(["balance", "--all", "--wallet", "default"]).is_err());
+    }
+
+    #[test]
+    fn test_holdings_row() {
+        let rao = Balance::from_rao;
+        let holdings = Holdings {
+            free: rao(100),
+            reserved: rao(20),
+            staked: rao(3),
+        };
+
+        let row = holdings.row("default".into(), "5F71".into()).unwrap();
+        assert_eq!(row[0], "default");
+        assert_eq!(row[3], rao(20).to_string());
+        assert_eq!(row[5], rao(123).to_string());
+
+        let total = Holdings::default()
+            .combine(&holdings)
+            .and_then(|total| total.combine(&holdings))
+            .unwrap();
+        assert_eq!(total.reserved, rao(40));
+        assert_eq!(total.total(), Some(rao(246)));
+
+        let full = Holdings {
+            free: rao(u64::MAX),
+            ..Holdings::default()
+        };
+        assert_eq!(full.combine(&holdings), None);
+        assert!(Holdings {
+            staked: rao(1),
+            ..full
+        }
+        .row("full".into(), String::new())
+        .is_err());
+    }
+}
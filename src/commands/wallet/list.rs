@@ -0,0 +1,98 @@
+use std::fmt::Write;
+
+use crate::{
+    commands::error::CommandError,
+    config,
+    keystore::wallet::{self, Wallet},
+};
+
+pub fn list_wallets(config: &config::Config) -> Result<(), CommandError> {
+    let wallets = wallet::list_wallets(&config.key_path)?;
+
+    if wallets.is_empty() {
+        println!("No wallets found in {:?}", config.key_path);
+        return Ok(());
+    }
+
+    print!(
+        "{}",
+        render_tree(&config.key_path.to_string_lossy(), &wallets)
+    );
+
+    Ok(())
+}
+
+/// renders wallets as a tree of wallet -> coldkey address -> hotkeys
+fn render_tree(root: &str, wallets: &[Wallet]) -> String {
+    let mut out = format!("{root}\n");
+
+    for (i, wallet) in wallets.iter().enumerate() {
+        let last_wallet = i + 1 == wallets.len();
+        let (branch, indent) = if last_wallet {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+
+        let coldkey = wallet
+            .coldkey_ss58
+            .as_deref()
+            .unwrap_or("no coldkeypub.txt");
+        let _ = writeln!(out, "{branch}{} ({coldkey})", wallet.name);
+
+        for (j, hotkey) in wallet.hotkeys.iter().enumerate() {
+            let branch = if j + 1 == wallet.hotkeys.len() {
+                "└── "
+            } else {
+                "├── "
+            };
+
+            let address = hotkey.ss58_address.as_deref().unwrap_or("unreadable");
+            let _ = writeln!(out, "{indent}{branch}{} ({address})", hotkey.name);
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keystore::wallet::Hotkey;
+
+    #[test]
+    fn test_render_tree() {
+        let wallets = vec![
+            Wallet {
+                name: "default".into(),
+                path: "/wallets/default".into(),
+                coldkey_ss58: Some("5Cold".into()),
+                hotkeys: vec![
+                    Hotkey {
+                        name: "miner1".into(),
+                        ss58_address: Some("5Hot1".into()),
+                    },
+                    Hotkey {
+                        name: "miner2".into(),
+                        ss58_address: None,
+                    },
+                ],
+            },
+            Wallet {
+                name: "watch".into(),
+                path: "/wallets/watch".into(),
+                coldkey_ss58: None,
+                hotkeys: vec![],
+            },
+        ];
+
+        assert_eq!(
+            render_tree("/wallets", &wallets),
+            "/wallets\n\
+             ├── default (5Cold)\n\
+             │   ├── miner1 (5Hot1)\n\
+             │   └── miner2 (unreadable)\n\
+             └── watch (no coldkeypub.txt)\n"
+        );
+    }
+}
@@ -0,0 +1,25 @@
+pub mod balance;
+pub mod list;
+pub mod overview;
+pub mod swap_hotkey;
+
+use clap::Subcommand;
+
+use self::{balance::BalanceArgs, overview::OverviewArgs, swap_hotkey::SwapHotkeyArgs};
+
+/// commands working on the wallets stored under `key_path`
+#[derive(Debug, Subcommand)]
+pub enum WalletCommands {
+    /// Show every wallet with its coldkey and hotkey addresses
+    List,
+
+    /// Show stake, UIDs and emissions of a wallet's hotkeys across all subnets
+    Overview(OverviewArgs),
+
+    /// Show free, reserved and staked balances of one or all wallets
+    Balance(BalanceArgs),
+
+    /// Move the registrations and stake of a hotkey to a new hotkey
+    #[command(arg_required_else_help = true)]
+    SwapHotkey(SwapHotkeyArgs),
+}
@@ -0,0 +1,236 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    str::FromStr,
+};
+
+use clap::Parser;
+use futures::future::try_join_all;
+use subxt::{utils::AccountId32, OnlineClient, SubstrateConfig};
+
+use crate::{
+    commands::{block::BlockArgs, error::CommandError, table::render_table},
+    config,
+    keystore::wallet,
+    subtensor::{
+        queries::{self, u16_to_fraction, NeuronVectors},
+        units::Balance,
+    },
+};
+
+#[derive(Debug, Parser)]
+pub struct OverviewArgs {
+    #[arg(
+        long = "wallet",
+        value_name = "STRING",
+        help = "Specifies the coldkey wallet to report on"
+    )]
+    pub wallet: Option<String>,
+
+    #[command(flatten)]
+    pub block: BlockArgs,
+}
+
+pub async fn overview(config: &config::Config, args: &OverviewArgs) -> Result<(), CommandError> {
+    let name = args
+        .wallet
+        .clone()
+        .or_else(|| config.default_coldkey.clone())
+        .ok_or_else(|| CommandError::Input("No wallet specified".into()))?;
+
+    let wallet = wallet::read_wallet(&config.key_path, &name)?;
+    let coldkey_ss58 = wallet
+        .coldkey_ss58
+        .ok_or_else(|| CommandError::Input(format!("Wallet {name} has no coldkeypub.txt")))?;
+    let coldkey = AccountId32::from_str(&coldkey_ss58)
+        .map_err(|e| CommandError::Input(format!("Invalid coldkey address: {e}")))?;
+
+    let mut hotkeys = Vec::new();
+    for hotkey in wallet.hotkeys {
+        match hotkey.ss58_address.as_deref().map(AccountId32::from_str) {
+            Some(Ok(account)) => hotkeys.push((hotkey.name, account)),
+            _ => eprintln!("Skipping unreadable hotkey {}", hotkey.name),
+        }
+    }
+
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+    let at = args.block.at(config, &client).await?;
+    let netuids = queries::subnets(&client, at).await?;
+
+    // every hotkey and subnet is queried at once rather than one round trip after another
+    let stakes = try_join_all(
+        hotkeys
+            .iter()
+            .map(|(_, hotkey)| queries::stake(&client, at, hotkey, &coldkey)),
+    )
+    .await?;
+    let registrations = try_join_all(hotkeys.iter().map(|(_, hotkey)| {
+        let netuids = &netuids;
+        let client = &client;
+        async move {
+            let uids = try_join_all(
+                netuids
+                    .iter()
+                    .map(|&netuid| queries::uid(client, at, netuid, hotkey)),
+            )
+            .await?;
+            Ok::<_, subxt::Error>(
+                netuids
+                    .iter()
+                    .zip(uids)
+                    .filter_map(|(&netuid, uid)| Some((netuid, uid?)))
+                    .collect::<Vec<_>>(),
+            )
+        }
+    }))
+    .await?;
+
+    let registered_netuids: BTreeSet<u16> = registrations
+        .iter()
+        .flatten()
+        .map(|&(netuid, _)| netuid)
+        .collect();
+    let vectors: HashMap<u16, NeuronVectors> =
+        try_join_all(registered_netuids.into_iter().map(|netuid| {
+            let client = &client;
+            async move {
+                let vectors = queries::neuron_vectors(client, at, netuid).await?;
+                Ok::<_, subxt::Error>((netuid, vectors))
+            }
+        }))
+        .await?
+        .into_iter()
+        .collect();
+
+    let mut rows = Vec::new();
+    let total_stake = Balance::checked_sum(stakes.iter().copied())
+        .ok_or_else(|| CommandError::Input("Total stake overflows".into()))?;
+    for (((hotkey_name, hotkey), stake), registrations) in
+        hotkeys.iter().zip(stakes).zip(&registrations)
+    {
+        rows.extend(hotkey_rows(
+            hotkey_name,
+            hotkey,
+            stake,
+            registrations,
+            &vectors,
+        ));
+    }
+
+    println!("Wallet: {name} ({coldkey_ss58})");
+    print!(
+        "{}",
+        render_table(
+            &[
+                "NETUID",
+                "HOTKEY",
+                "ADDRESS",
+                "UID",
+                "STAKE",
+                "RANK",
+                "TRUST",
+                "INCENTIVE",
+                "DIVIDENDS",
+                "EMISSION",
+                "ACTIVE",
+            ],
+            &rows,
+        )
+    );
+    println!("Total stake: {total_stake}");
+
+    Ok(())
+}
+
+/// one row per subnet the hotkey is registered on, its stake is only shown on the first one
+/// since it is not split between subnets
+fn hotkey_rows(
+    name: &str,
+    hotkey: &AccountId32,
+    stake: Balance,
+    registrations: &[(u16, u16)],
+    vectors: &HashMap<u16, NeuronVectors>,
+) -> Vec<Vec<String>> {
+    if registrations.is_empty() {
+        let mut row = vec![
+            "-".to_string(),
+            name.to_string(),
+            hotkey.to_string(),
+            "-".to_string(),
+            stake.to_string(),
+        ];
+        row.resize(11, "-".to_string());
+        return vec![row];
+    }
+
+    registrations
+        .iter()
+        .enumerate()
+        .map(|(position, &(netuid, uid))| {
+            let default = NeuronVectors::default();
+            let subnet = vectors.get(&netuid).unwrap_or(&default);
+            let index = usize::from(uid);
+            let fraction = |values: &[u16]| {
+                format!(
+                    "{:.5}",
+                    u16_to_fraction(values.get(index).copied().unwrap_or_default())
+                )
+            };
+
+            vec![
+                netuid.to_string(),
+                name.to_string(),
+                hotkey.to_string(),
+                uid.to_string(),
+                if position == 0 {
+                    stake.to_string()
+                } else {
+                    String::new()
+                },
+                fraction(&subnet.rank),
+                fraction(&subnet.trust),
+                fraction(&subnet.incentive),
+                fraction(&subnet.dividends),
+                Balance::from_rao(subnet.emission.get(index).copied().unwrap_or_default())
+                    .to_string(),
+                subnet
+                    .active
+                    .get(index)
+                    .copied()
+                    .unwrap_or_default()
+                    .to_string(),
+            ]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hotkey_rows() {
+        let hotkey = AccountId32([1; 32]);
+        let stake = Balance::from_tao(5).unwrap();
+        let vectors = HashMap::from([(
+            3,
+            NeuronVectors {
+                emission: vec![0, 0, 7],
+                active: vec![false, false, true],
+                ..Default::default()
+            },
+        )]);
+
+        let rows = hotkey_rows("miner", &hotkey, stake, &[(1, 0), (3, 2)], &vectors);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0][4], stake.to_string());
+        // the stake is not repeated on every subnet
+        assert_eq!(rows[1][4], "");
+        assert_eq!(rows[1][9], Balance::from_rao(7).to_string());
+        assert_eq!(rows[1][10], "true");
+
+        let rows = hotkey_rows("idle", &hotkey, stake, &[], &vectors);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0][0], "-");
+        assert_eq!(rows[0][4], stake.to_string());
+    }
+}
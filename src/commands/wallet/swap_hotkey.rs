@@ -0,0 +1,140 @@
+use clap::Parser;
+use dialoguer::Confirm;
+use subxt::{OnlineClient, SubstrateConfig};
+
+use crate::{
+    commands::{error::CommandError, submit::preview},
+    config,
+    keystore::{
+        cli::{unlock_coldkey, PasswordArgs},
+        error::KeystoreError,
+    },
+    subtensor::{address::Address, block::At, interface::api, queries},
+};
+
+#[derive(Debug, Parser)]
+pub struct SwapHotkeyArgs {
+    // hotkey name under the wallet or ss58 address
+    #[arg(
+        long = "old",
+        value_name = "STRING",
+        help = "Name or SS58 address of the hotkey to replace"
+    )]
+    pub old: Address,
+
+    // hotkey name under the wallet or ss58 address
+    #[arg(
+        long = "new",
+        value_name = "STRING",
+        help = "Name or SS58 address of the replacement hotkey"
+    )]
+    pub new: Address,
+
+    // coldkey
+    #[arg(
+        long = "wallet",
+        value_name = "STRING",
+        help = "Specifies the coldkey wallet that owns the hotkeys"
+    )]
+    pub wallet: Option<String>,
+
+    #[command(flatten)]
+    pub password_params: PasswordArgs,
+}
+
+pub async fn swap_hotkey(
+    config: &config::Config,
+    args: &SwapHotkeyArgs,
+) -> Result<(), CommandError> {
+    let coldkey = args
+        .wallet
+        .clone()
+        .or_else(|| config.default_coldkey.clone())
+        .ok_or_else(|| CommandError::Input("No coldkey specified".into()))?;
+
+    let old_hotkey = args.old.hotkey_account_id(&config.key_path, &coldkey)?;
+    let new_hotkey = args.new.hotkey_account_id(&config.key_path, &coldkey)?;
+    if old_hotkey == new_hotkey {
+        return Err(CommandError::Input(
+            "Old and new hotkey are the same".into(),
+        ));
+    }
+
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+
+    let keystore = unlock_coldkey(
+        &config.key_path.join(&coldkey),
+        args.password_params.password.clone(),
+    )?;
+    let signer = keystore.to_keypair()?;
+    let account_id = signer.public_key().to_account_id();
+
+    match queries::owner(&client, At::Latest, &old_hotkey).await? {
+        Some(owner) if owner == account_id => {}
+        Some(owner) => {
+            return Err(CommandError::Input(format!(
+                "Hotkey {old_hotkey} is owned by {owner}, not by {account_id}"
+            )))
+        }
+        None => {
+            return Err(CommandError::Input(format!(
+                "Hotkey {old_hotkey} is not registered"
+            )))
+        }
+    }
+
+    if let Some(owner) = queries::owner(&client, At::Latest, &new_hotkey).await? {
+        return Err(CommandError::Input(format!(
+            "Hotkey {new_hotkey} is already owned by {owner}"
+        )));
+    }
+    for netuid in queries::subnets(&client, At::Latest).await? {
+        if let Some(uid) = queries::uid(&client, At::Latest, netuid, &new_hotkey).await? {
+            return Err(CommandError::Input(format!(
+                "Hotkey {new_hotkey} is already registered on subnet {netuid} with uid {uid}"
+            )));
+        }
+    }
+
+    println!("Coldkey:    {coldkey} ({account_id})");
+    println!("Old hotkey: {old_hotkey}");
+    println!("New hotkey: {new_hotkey}");
+
+    let swap_hotkey_tx = api::tx()
+        .subtensor_module()
+        .swap_hotkey(old_hotkey, new_hotkey);
+
+    if config.dry_run {
+        preview(&client, &swap_hotkey_tx, &signer).await?;
+        println!("Dry run, nothing submitted");
+        return Ok(());
+    }
+
+    let confirmed = Confirm::new()
+        .with_prompt("Move all registrations and stake to the new hotkey?")
+        .default(false)
+        .interact()
+        .map_err(KeystoreError::DialogueError)?;
+
+    if !confirmed {
+        println!("Hotkey swap cancelled");
+        return Ok(());
+    }
+
+    let events = client
+        .tx()
+        .sign_and_submit_then_watch_default(&swap_hotkey_tx, &signer)
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    match events.find_first::<api::subtensor_module::events::HotkeySwapped>()? {
+        Some(event) => println!(
+            "Hotkey swapped for {}: {} -> {}",
+            event.coldkey, event.old_hotkey, event.new_hotkey
+        ),
+        None => println!("Transaction finalized without a HotkeySwapped event"),
+    }
+
+    Ok(())
+}
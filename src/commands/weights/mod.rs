@@ -0,0 +1,13 @@
+pub mod set;
+
+use clap::Subcommand;
+
+use self::set::SetWeightsArgs;
+
+/// commands for a neuron's weights on a subnet
+#[derive(Debug, Subcommand)]
+pub enum WeightsCommands {
+    /// Normalize and set weights on a subnet
+    #[command(arg_required_else_help = true)]
+    Set(SetWeightsArgs),
+}
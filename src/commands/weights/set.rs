@@ -0,0 +1,403 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use clap::Parser;
+use dialoguer::Confirm;
+use serde::{
+    de::{MapAccess, Visitor},
+    Deserialize, Deserializer,
+};
+use subxt::{OnlineClient, SubstrateConfig};
+
+use crate::{
+    commands::{error::CommandError, submit::preview, table::render_table},
+    config,
+    keystore::error::KeystoreError,
+    subtensor::{
+        address::Address,
+        block::At,
+        interface::api,
+        queries,
+        weights::{check_weight_limits, find_duplicate, normalize_weights},
+    },
+};
+
+#[derive(Debug, Parser)]
+pub struct SetWeightsArgs {
+    #[arg(
+        long = "netuid",
+        value_name = "NETUID",
+        help = "Subnet to set weights on"
+    )]
+    pub netuid: u16,
+
+    #[arg(
+        long = "uids",
+        value_name = "UIDS",
+        value_delimiter = ',',
+        conflicts_with = "file",
+        required_unless_present = "file",
+        requires = "weights",
+        help = "Comma separated uids to weight"
+    )]
+    pub uids: Vec<u16>,
+
+    #[arg(
+        long = "weights",
+        value_name = "WEIGHTS",
+        value_delimiter = ',',
+        conflicts_with = "file",
+        requires = "uids",
+        help = "Comma separated weights, one per uid"
+    )]
+    pub weights: Vec<f64>,
+
+    #[arg(
+        long = "file",
+        value_name = "FILE_PATH",
+        help = "JSON object of uid to weight, or CSV with uid,weight rows"
+    )]
+    pub file: Option<PathBuf>,
+
+    // coldkey
+    #[arg(
+        long = "wallet",
+        value_name = "STRING",
+        help = "Specifies the coldkey wallet the hotkey belongs to"
+    )]
+    pub wallet: Option<String>,
+
+    // hotkey name under the wallet or ss58 address
+    #[arg(
+        long = "hotkey",
+        value_name = "STRING",
+        help = "Name of a hotkey under the wallet or its SS58 address, setting the weights"
+    )]
+    pub hotkey: Option<Address>,
+}
+
+pub async fn set_weights(
+    config: &config::Config,
+    args: &SetWeightsArgs,
+) -> Result<(), CommandError> {
+    let (uids, raw_weights) = match &args.file {
+        Some(path) => read_weights_file(path)?,
+        None => (args.uids.clone(), args.weights.clone()),
+    };
+    if uids.len() != raw_weights.len() {
+        return Err(CommandError::Input(format!(
+            "Got {} uids but {} weights",
+            uids.len(),
+            raw_weights.len()
+        )));
+    }
+    if let Some(uid) = find_duplicate(&uids) {
+        return Err(CommandError::Input(format!(
+            "Uid {uid} is given more than once"
+        )));
+    }
+    let weights = normalize_weights(&raw_weights).map_err(CommandError::Input)?;
+
+    let wallet_name = args
+        .wallet
+        .clone()
+        .or_else(|| config.default_coldkey.clone())
+        .ok_or_else(|| CommandError::Input("No wallet specified".into()))?;
+    let hotkey_name = args
+        .hotkey
+        .clone()
+        .or_else(|| config.default_hotkey.clone().map(Address::Name))
+        .ok_or_else(|| CommandError::Input("No hotkey specified".into()))?;
+
+    // weights are set by the hotkey itself
+    let signer = hotkey_name
+        .hotkey_keystore(&config.key_path, &wallet_name)?
+        .to_keypair()?;
+    let hotkey = signer.public_key().to_account_id();
+
+    let client = OnlineClient::<SubstrateConfig>::from_url(&config.subtensor_endpoint).await?;
+    let netuid = args.netuid;
+
+    if !queries::subnets(&client, At::Latest)
+        .await?
+        .contains(&netuid)
+    {
+        return Err(CommandError::Input(format!(
+            "Subnet {netuid} does not exist"
+        )));
+    }
+    let uid = queries::uid(&client, At::Latest, netuid, &hotkey)
+        .await?
+        .ok_or_else(|| {
+            CommandError::Input(format!(
+                "Hotkey {hotkey_name} is not registered on subnet {netuid}"
+            ))
+        })?;
+
+    let n = queries::subnetwork_n(&client, At::Latest, netuid).await?;
+    if let Some(dest) = uids.iter().find(|dest| **dest >= n) {
+        return Err(CommandError::Input(format!(
+            "Uid {dest} does not exist, subnet {netuid} has {n} neurons"
+        )));
+    }
+
+    let min_allowed_weights = queries::min_allowed_weights(&client, At::Latest, netuid).await?;
+    let max_weights_limit = queries::max_weights_limit(&client, At::Latest, netuid).await?;
+    check_weight_limits(
+        &uids,
+        &weights,
+        uid,
+        n,
+        min_allowed_weights,
+        max_weights_limit,
+    )
+    .map_err(CommandError::Input)?;
+
+    let version_key = queries::weights_version_key(&client, At::Latest, netuid).await?;
+
+    let current_block = u64::from(client.blocks().at_latest().await?.number());
+    let last_update = queries::last_update(&client, At::Latest, netuid)
+        .await?
+        .get(usize::from(uid))
+        .copied()
+        .unwrap_or_default();
+    let rate_limit = queries::weights_set_rate_limit(&client, At::Latest, netuid).await?;
+    if current_block < last_update.saturating_add(rate_limit) {
+        eprintln!(
+            "Warning: weights were last set at block {last_update}, the rate limit of {rate_limit} blocks \
+             has not passed yet (current block {current_block}), the chain will likely reject this"
+        );
+    }
+
+    println!("Hotkey: {hotkey_name} ({hotkey}), uid {uid} on subnet {netuid}");
+    println!("Version key: {version_key}");
+    let rows: Vec<_> = uids
+        .iter()
+        .zip(&raw_weights)
+        .zip(&weights)
+        .map(|((dest, weight), normalized)| {
+            vec![dest.to_string(), weight.to_string(), normalized.to_string()]
+        })
+        .collect();
+    print!("{}", render_table(&["UID", "WEIGHT", "U16"], &rows));
+
+    let set_weights_tx =
+        api::tx()
+            .subtensor_module()
+            .set_weights(netuid, uids, weights, version_key);
+
+    if config.dry_run {
+        preview(&client, &set_weights_tx, &signer).await?;
+        println!("Dry run, nothing submitted");
+        return Ok(());
+    }
+
+    let confirmed = Confirm::new()
+        .with_prompt("Set these weights?")
+        .default(false)
+        .interact()
+        .map_err(KeystoreError::DialogueError)?;
+
+    if !confirmed {
+        println!("Setting weights cancelled");
+        return Ok(());
+    }
+
+    let events = client
+        .tx()
+        .sign_and_submit_then_watch_default(&set_weights_tx, &signer)
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    if let Some(event) = events.find_first::<api::subtensor_module::events::WeightsSet>()? {
+        println!("Weights set for uid {} on netuid {}", event.1, event.0);
+    }
+
+    Ok(())
+}
+
+/// reads uids and weights from a `.json` or `.csv` file
+fn read_weights_file(path: &Path) -> Result<(Vec<u16>, Vec<f64>), CommandError> {
+    let contents = fs::read_to_string(path).map_err(KeystoreError::Io)?;
+
+    let weights = match path.extension().and_then(|e| e.to_str()) {
+        Some("json") => parse_weights_json(&contents),
+        Some("csv") => parse_weights_csv(&contents),
+        _ => Err(format!(
+            "Unsupported weights file {path:?}, expected .json or .csv"
+        )),
+    }
+    .map_err(CommandError::Input)?;
+
+    Ok(weights.into_iter().unzip())
+}
+
+/// parses a JSON object mapping uids to weights, e.g. `{"0": 0.5, "3": 0.5}`
+fn parse_weights_json(contents: &str) -> Result<BTreeMap<u16, f64>, String> {
+    let JsonEntries(entries) =
+        serde_json::from_str(contents).map_err(|e| format!("Invalid weights JSON: {e}"))?;
+
+    let mut weights = BTreeMap::new();
+    for (uid, weight) in entries {
+        let uid = uid
+            .parse()
+            .map_err(|_| format!("Invalid uid in weights JSON: {uid}"))?;
+        let weight = weight
+            .as_f64()
+            .ok_or_else(|| format!("Invalid weight for uid {uid}: {weight}"))?;
+
+        if weights.insert(uid, weight).is_some() {
+            return Err(format!("Duplicate uid {uid} in weights JSON"));
+        }
+    }
+
+    Ok(weights)
+}
+
+/// members of a JSON object in file order, unlike `serde_json::Map` it keeps duplicate keys
+struct JsonEntries(Vec<(String, serde_json::Value)>);
+
+impl<'de> Deserialize<'de> for JsonEntries {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct EntriesVisitor;
+
+        impl<'de> Visitor<'de> for EntriesVisitor {
+            type Value = JsonEntries;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("an object of uids to weights")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<JsonEntries, A::Error> {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(JsonEntries(entries))
+            }
+        }
+
+        deserializer.deserialize_map(EntriesVisitor)
+    }
+}
+
+/// parses `uid,weight` rows, an optional header row is skipped
+fn parse_weights_csv(contents: &str) -> Result<BTreeMap<u16, f64>, String> {
+    let mut weights = BTreeMap::new();
+
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let (uid, weight) = line
+            .split_once(',')
+            .ok_or_else(|| format!("Line {}: expected uid,weight", index + 1))?;
+        let (uid, weight) = (uid.trim(), weight.trim());
+
+        let Ok(uid) = uid.parse::<u16>() else {
+            if index == 0 {
+                continue;
+            }
+            return Err(format!("Line {}: invalid uid {uid}", index + 1));
+        };
+        let weight = weight
+            .parse()
+            .map_err(|_| format!("Line {}: invalid weight {weight}", index + 1))?;
+
+        if weights.insert(uid, weight).is_some() {
+            return Err(format!("Line {}: duplicate uid {uid}", index + 1));
+        }
+    }
+
+    Ok(weights)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_weights_json() {
+        let weights = parse_weights_json(r#"{"3": 0.25, "0": 1}"#).unwrap();
+        assert_eq!(
+            weights.into_iter().collect::<Vec<_>>(),
+            vec![(0, 1.0), (3, 0.25)]
+        );
+
+        assert!(parse_weights_json(r#"{"a": 1}"#).is_err());
+        assert!(parse_weights_json(r#"{"1": "x"}"#).is_err());
+        assert!(parse_weights_json("[1, 2]").is_err());
+        assert!(parse_weights_json(r#"{"1": 0.5, "1": 0.25}"#).is_err());
+        assert!(parse_weights_json(r#"{"1": 0.5, "01": 0.25}"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_weights_csv() {
+        let weights = parse_weights_csv("uid,weight\n5, 0.5\n\n1,2\n").unwrap();
+        assert_eq!(
+            weights.into_iter().collect::<Vec<_>>(),
+            vec![(1, 2.0), (5, 0.5)]
+        );
+
+        assert!(parse_weights_csv("1,1\n1,2\n").is_err());
+        assert!(parse_weights_csv("1,1\nx,2\n").is_err());
+        assert!(parse_weights_csv("1;1\n").is_err());
+    }
+
+    #[test]
+    fn test_set_weights_args() {
+        let args = SetWeightsArgs::try_parse_from([
+            "set",
+            "--netuid",
+            "1",
+            "--uids",
+            "0,4",
+            "--weights",
+            "1,0.5",
+        ])
+        .unwrap();
+        assert_eq!(args.uids, vec![0, 4]);
+        assert_eq!(args.weights, vec![1.0, 0.5]);
+
+        assert!(
+            SetWeightsArgs::try_parse_from(["set", "--netuid", "1", "--file", "w.json"]).is_ok()
+        );
+        assert!(SetWeightsArgs::try_parse_from(["set", "--netuid", "1"]).is_err());
+        assert!(SetWeightsArgs::try_parse_from([
+            "set",
+            "--netuid",
+            "1",
+            "--uids",
+            "0",
+            "--weights",
+            "1",
+            "--file",
+            "w.json",
+        ])
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_set_weights_rejects_duplicate_uids() {
+        let args = SetWeightsArgs::try_parse_from([
+            "set",
+            "--netuid",
+            "1",
+            "--uids",
+            "1,1",
+            "--weights",
+            "0.5,0.5",
+        ])
+        .unwrap();
+
+        // rejected before any key is read or the chain is contacted
+        let result = set_weights(&config::Config::default(), &args).await;
+        assert!(matches!(result, Err(CommandError::Input(message)) if message.contains("Uid 1")));
+    }
+}
@@ -3,5 +3,5 @@ use thiserror::Error;
 #[derive(Error, Debug)]
 pub enum ConfigError {
     #[error("Invalid configuration")]
-    Invalid(#[source] figment::Error),
+    Invalid(#[source] Box<figment::Error>),
 }
@@ -17,6 +17,8 @@ pub struct Config {
     pub default_coldkey: Option<String>,
     pub default_hotkey: Option<String>,
     pub subtensor_endpoint: String,
+    #[serde(default)]
+    pub dry_run: bool,
 }
 
 impl Default for Config {
@@ -27,6 +29,7 @@ impl Default for Config {
             subtensor_endpoint: "wss://entrypoint-finney.opentensor.ai:443".to_string(),
             default_coldkey: None,
             default_hotkey: None,
+            dry_run: false,
         }
     }
 }
@@ -57,6 +60,9 @@ impl Config {
         if let Some(subtensor_endpoint) = &args.subtensor_endpoint {
             self.subtensor_endpoint = subtensor_endpoint.clone();
         }
+        if args.dry_run {
+            self.dry_run = true;
+        }
     }
 
     pub fn merge_with_transfer_args(&mut self, args: &TransferArgs) {
@@ -69,13 +75,16 @@ impl Config {
         use figment::providers::{Env, Toml};
 
         let figment = Figment::from(Config::default())
-            .merge(Toml::file(&self.config_path.clone()))
+            .merge(Toml::file(&self.config_path))
             .merge(Env::prefixed("TENSORS_"));
 
-        let mut new_config: Config = figment.extract().map_err(error::ConfigError::Invalid)?;
+        let mut new_config: Config = figment
+            .extract()
+            .map_err(|e| error::ConfigError::Invalid(Box::new(e)))?;
 
         // make sure user cannot re-configure path from path
         new_config.config_path = self.config_path.clone();
+        new_config.dry_run |= self.dry_run;
 
         *self = new_config;
 
@@ -85,7 +94,7 @@ impl Config {
     pub fn from<T: Provider>(provider: T) -> Result<Config, error::ConfigError> {
         Figment::from(provider)
             .extract()
-            .map_err(error::ConfigError::Invalid)
+            .map_err(|e| error::ConfigError::Invalid(Box::new(e)))
     }
 
     pub fn figment() -> Figment {
@@ -1,13 +1,16 @@
-use crate::keystore::error::KeystoreError;
+use crate::keystore::{error::KeystoreError, Keystore};
 use clap::Args;
 use dialoguer::Input;
+use std::path::Path;
 
 use sp_core::crypto::SecretString;
 
+/// Number of times the interactive prompt asks for a password before giving up.
+const MAX_PASSWORD_ATTEMPTS: usize = 3;
+
 /// Parameters of the keystore
 #[derive(Debug, Clone, Args)]
 pub struct KeystoreArgs {
-
     /// Use interactive shell for entering the name of the keystore.
     #[arg(long, 
           default_value = "true",
@@ -25,7 +28,6 @@ pub struct KeystoreArgs {
     )]
     pub name: Option<String>,
 
-
     /// Use interactive shell for entering the password used by the keystore.
     #[arg(long, 
           default_value = "true",
@@ -52,22 +54,16 @@ pub fn secret_string_from_str(s: &str) -> std::result::Result<SecretString, Stri
 }
 
 impl KeystoreArgs {
-
     pub fn read_name(&self) -> Result<String, KeystoreError> {
-
         let (name_interactive, name) = (self.name_interactive, self.name.clone());
-        let name = if name_interactive {
-            let name = Input::<String>::new()
+        let name = match name {
+            // an explicit name always wins over the interactive default
+            Some(name) => name,
+            None if name_interactive => Input::<String>::new()
                 .with_prompt("Enter the name of the keystore")
                 .interact()
-                .map_err(|e| KeystoreError::DialogueError(e))?;
-
-            name
-        } else {
-            match name {
-                Some(name) => name,
-                None => return Err(KeystoreError::InvalidName),
-            }
+                .map_err(KeystoreError::DialogueError)?,
+            None => return Err(KeystoreError::InvalidName),
         };
 
         Ok(name)
@@ -76,16 +72,81 @@ impl KeystoreArgs {
     pub fn read_password(&self) -> Result<Option<SecretString>, KeystoreError> {
         let (password_interactive, password) = (self.password_interactive, self.password.clone());
 
-        let pass = if password_interactive {
-            let password =
-                rpassword::prompt_password("Key password: ").map_err(|e| KeystoreError::Io(e))?;
-            Some(SecretString::new(password))
-        } else {
-            password
+        let pass = match password {
+            Some(password) => Some(password),
+            None if password_interactive => {
+                let password =
+                    rpassword::prompt_password("Key password: ").map_err(KeystoreError::Io)?;
+                Some(SecretString::new(password))
+            }
+            None => None,
         };
 
         Ok(pass)
     }
+}
 
+/// Password of the coldkey a command signs with.
+#[derive(Debug, Clone, Args)]
+pub struct PasswordArgs {
+    /// Password of the coldkey, prompted for when not given.
+    #[arg(
+        long,
+        value_parser = secret_string_from_str,
+        value_name = "PASSWORD",
+        help = "Password of the coldkey, prompted for when not given"
+    )]
+    pub password: Option<SecretString>,
+}
+
+/// Decrypt the coldkey stored under `path` with `password`, or prompt for the password when
+/// none was given, asking again on a wrong one.
+pub fn unlock_coldkey(
+    path: &Path,
+    password: Option<SecretString>,
+) -> Result<Keystore, KeystoreError> {
+    if let Some(password) = password {
+        return Keystore::new_from_disk(path, Some(password));
+    }
+
+    let mut attempt = 1;
+
+    loop {
+        let password =
+            rpassword::prompt_password("Coldkey password: ").map_err(KeystoreError::Io)?;
+
+        match Keystore::new_from_disk(path, Some(SecretString::new(password))) {
+            Err(KeystoreError::WrongPassword) if attempt < MAX_PASSWORD_ATTEMPTS => {
+                eprintln!(
+                    "Wrong password, {} attempt(s) left",
+                    MAX_PASSWORD_ATTEMPTS - attempt
+                );
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlock_coldkey_with_password() {
+        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+        let fixture = Path::new(&manifest_dir).join("resources/testkey");
+
+        // a given password is used as is, nothing is prompted for
+        let keystore =
+            unlock_coldkey(&fixture, Some(SecretString::new("Password1%".into()))).unwrap();
+        assert_eq!(
+            keystore.ss58_address(),
+            "5F71GxBcHF9UfE6uiDcFsgbPNmHJGwPtNfg3F87HEeVczotP"
+        );
+        assert!(matches!(
+            unlock_coldkey(&fixture, Some(SecretString::new("Password1".into()))),
+            Err(KeystoreError::WrongPassword)
+        ));
+    }
+}
@@ -1,3 +1,7 @@
+use aes::cipher::{block_padding::Pkcs7, BlockDecryptMut, KeyIvInit, StreamCipher};
+use base64::{engine::general_purpose::URL_SAFE, Engine};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
 use sodiumoxide::crypto::{pwhash::argon2i13, secretbox};
 
 use super::error::KeystoreError;
@@ -6,13 +10,39 @@ const NACL_SALT: [u8; 16] = [
     0x13, 0x71, 0x83, 0xdf, 0xf1, 0x5a, 0x09, 0xbc, 0x9c, 0x90, 0xb5, 0x51, 0x87, 0x39, 0xe9, 0xb1,
 ];
 
-#[derive(Debug)]
+// salt and iteration count used by the Fernet keyfiles of early btcli versions
+const LEGACY_SALT: &[u8] = b"Iguesscyborgslikemyselfhaveatendencytobeparanoidaboutourorigins";
+const LEGACY_ITERATIONS: u32 = 10_000_000;
+
+// ansible vault 1.1 derives an AES256 key, an HMAC key and the CTR iv in one go
+const ANSIBLE_ITERATIONS: u32 = 10_000;
+const ANSIBLE_KEY_LENGTH: usize = 32;
+const ANSIBLE_IV_LENGTH: usize = 16;
+
+type HmacSha256 = Hmac<Sha256>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EncryptionType {
     Legacy,
     Ansible,
     Nacl,
 }
 
+impl EncryptionType {
+    /// detects the encryption of a keyfile from its header, as btcli does
+    pub fn from_header(data: &[u8]) -> Option<Self> {
+        if data.starts_with(b"$NACL") {
+            Some(EncryptionType::Nacl)
+        } else if data.starts_with(b"$ANSIBLE_VAULT") {
+            Some(EncryptionType::Ansible)
+        } else if data.starts_with(b"gAAAAA") {
+            Some(EncryptionType::Legacy)
+        } else {
+            None
+        }
+    }
+}
+
 pub fn encrypt(
     data: &str,
     password: &str,
@@ -28,16 +58,14 @@ pub fn encrypt(
 }
 
 pub fn decrypt(
-    data: &Vec<u8>,
+    data: &[u8],
     password: &str,
     encryption_type: EncryptionType,
 ) -> Result<Vec<u8>, KeystoreError> {
     match encryption_type {
-        EncryptionType::Nacl => {
-            let decrypted_data = decrypt_nacl(data, password);
-            Ok(decrypted_data)
-        }
-        _ => Err(KeystoreError::UnsupportedEncryptionType),
+        EncryptionType::Nacl => decrypt_nacl(data, password),
+        EncryptionType::Ansible => decrypt_ansible(data, password),
+        EncryptionType::Legacy => decrypt_legacy(data, password),
     }
 }
 
@@ -57,7 +85,7 @@ fn get_nacl_key(password: &str) -> secretbox::Key {
     )
     .unwrap();
 
-    secretbox::Key::from_slice(&key_bytes).unwrap()
+    secretbox::Key::from_slice(key_bytes).unwrap()
 }
 
 fn encrypt_nacl(data: &str, password: &str) -> Vec<u8> {
@@ -68,7 +96,7 @@ fn encrypt_nacl(data: &str, password: &str) -> Vec<u8> {
 
     let nonce = secretbox::gen_nonce();
 
-    let encrypted_data = secretbox::seal(&mut data.as_bytes(), &nonce, &key);
+    let encrypted_data = secretbox::seal(data.as_bytes(), &nonce, &key);
 
     let mut output = "$NACL".as_bytes().to_vec();
     output.extend_from_slice(&nonce.0);
@@ -79,37 +107,142 @@ fn encrypt_nacl(data: &str, password: &str) -> Vec<u8> {
 
 fn extract_nonce_from_encryption_type(
     encryption_type: EncryptionType,
-    data: &Vec<u8>,
-) -> secretbox::Nonce {
+    data: &[u8],
+) -> Result<secretbox::Nonce, KeystoreError> {
     match encryption_type {
         EncryptionType::Nacl => extract_nonce_nacl(data), // Skip the "$NACL" prefix
-        _ => panic!("Unsupported encryption type"),
+        _ => Err(KeystoreError::UnsupportedEncryptionType),
     }
 }
 
-fn extract_nonce_nacl(data: &Vec<u8>) -> secretbox::Nonce {
+fn extract_nonce_nacl(data: &[u8]) -> Result<secretbox::Nonce, KeystoreError> {
     let nonce_start = 5; // Skip the "$NACL" prefix
     let nonce_end = nonce_start + secretbox::NONCEBYTES; // secretbox::NONCEBYTES should be 24
-    let nonce_slice = &data[nonce_start..nonce_end];
-    let nonce_array: [u8; 24] = nonce_slice.try_into().expect("Slice with incorrect length"); // Convert the slice into an array
-    secretbox::Nonce(nonce_array)
+    let nonce_slice = data
+        .get(nonce_start..nonce_end)
+        .ok_or(KeystoreError::CorruptKeyfile)?;
+    let nonce_array: [u8; 24] = nonce_slice
+        .try_into()
+        .map_err(|_| KeystoreError::CorruptKeyfile)?;
+    Ok(secretbox::Nonce(nonce_array))
 }
 
-fn extract_encrypted_data_nacl(data: &Vec<u8>) -> Vec<u8> {
+fn extract_encrypted_data_nacl(data: &[u8]) -> Result<Vec<u8>, KeystoreError> {
     let nonce_end = 5 + secretbox::NONCEBYTES; // secretbox::NONCEBYTES should be 24
-    data[nonce_end..].to_vec()
+
+    // anything shorter than the MAC cannot have been produced by `encrypt_nacl`
+    match data.get(nonce_end..) {
+        Some(encrypted) if encrypted.len() >= secretbox::MACBYTES => Ok(encrypted.to_vec()),
+        _ => Err(KeystoreError::CorruptKeyfile),
+    }
 }
 
-pub fn decrypt_nacl(data: &Vec<u8>, password: &str) -> Vec<u8> {
+pub fn decrypt_nacl(data: &[u8], password: &str) -> Result<Vec<u8>, KeystoreError> {
+    if !data.starts_with(b"$NACL") {
+        return Err(KeystoreError::UnknownFormat);
+    }
+
+    // check the layout before paying for the key derivation
+    let nonce = extract_nonce_from_encryption_type(EncryptionType::Nacl, data)?;
+    let encrypted_data = extract_encrypted_data_nacl(data)?;
+
     // todo: find a clever place to put this
     sodiumoxide::init().unwrap();
     let key = get_nacl_key(password);
-    let nonce = extract_nonce_from_encryption_type(EncryptionType::Nacl, data);
-    let encrypted_data = extract_encrypted_data_nacl(data);
 
-    let decrypted = secretbox::open(&encrypted_data, &nonce, &key).unwrap();
+    secretbox::open(&encrypted_data, &nonce, &key).map_err(|_| KeystoreError::WrongPassword)
+}
+
+fn get_legacy_key(password: &str, iterations: u32) -> [u8; 32] {
+    let mut key = [0; 32];
+    pbkdf2::pbkdf2_hmac::<Sha256>(password.as_bytes(), LEGACY_SALT, iterations, &mut key);
+    key
+}
 
-    decrypted
+fn decrypt_legacy(data: &[u8], password: &str) -> Result<Vec<u8>, KeystoreError> {
+    let key = get_legacy_key(password, LEGACY_ITERATIONS);
+    decrypt_fernet(data, &key)
+}
+
+/// decrypts a Fernet token, the first half of the key signs and the second half encrypts
+fn decrypt_fernet(data: &[u8], key: &[u8; 32]) -> Result<Vec<u8>, KeystoreError> {
+    let token = URL_SAFE
+        .decode(data.trim_ascii())
+        .map_err(|_| KeystoreError::CorruptKeyfile)?;
+
+    // version (1) | timestamp (8) | iv (16) | ciphertext (16 * n) | hmac (32)
+    if token.len() < 1 + 8 + 16 + 16 + 32 || token[0] != 0x80 {
+        return Err(KeystoreError::CorruptKeyfile);
+    }
+
+    let (signed, tag) = token.split_at(token.len() - 32);
+    let mut mac = HmacSha256::new_from_slice(&key[..16]).expect("HMAC accepts any key length");
+    mac.update(signed);
+    mac.verify_slice(tag)
+        .map_err(|_| KeystoreError::WrongPassword)?;
+
+    let (iv, ciphertext) = signed[9..].split_at(16);
+
+    cbc::Decryptor::<aes::Aes128>::new(key[16..].into(), iv.into())
+        .decrypt_padded_vec_mut::<Pkcs7>(ciphertext)
+        .map_err(|_| KeystoreError::CorruptKeyfile)
+}
+
+/// decrypts an ansible vault 1.1 keyfile, see `ansible.parsing.vault.VaultAES256`
+fn decrypt_ansible(data: &[u8], password: &str) -> Result<Vec<u8>, KeystoreError> {
+    let text = std::str::from_utf8(data).map_err(|_| KeystoreError::CorruptKeyfile)?;
+    let mut lines = text.lines();
+
+    let header = lines.next().ok_or(KeystoreError::CorruptKeyfile)?;
+    let mut header = header.trim().split(';');
+    if header.next() != Some("$ANSIBLE_VAULT") {
+        return Err(KeystoreError::CorruptKeyfile);
+    }
+    if header.nth(1) != Some("AES256") {
+        return Err(KeystoreError::UnsupportedEncryptionType);
+    }
+
+    // the envelope is hex of "hex(salt)\nhex(hmac)\nhex(ciphertext)"
+    let body = hex::decode(lines.map(str::trim).collect::<String>())
+        .map_err(|_| KeystoreError::CorruptKeyfile)?;
+    let body = std::str::from_utf8(&body).map_err(|_| KeystoreError::CorruptKeyfile)?;
+    let parts = body
+        .split('\n')
+        .map(hex::decode)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| KeystoreError::CorruptKeyfile)?;
+
+    let [salt, tag, ciphertext] = parts.as_slice() else {
+        return Err(KeystoreError::CorruptKeyfile);
+    };
+
+    let mut derived = [0; 2 * ANSIBLE_KEY_LENGTH + ANSIBLE_IV_LENGTH];
+    pbkdf2::pbkdf2_hmac::<Sha256>(password.as_bytes(), salt, ANSIBLE_ITERATIONS, &mut derived);
+    let (cipher_key, rest) = derived.split_at(ANSIBLE_KEY_LENGTH);
+    let (hmac_key, iv) = rest.split_at(ANSIBLE_KEY_LENGTH);
+
+    let mut mac = HmacSha256::new_from_slice(hmac_key).expect("HMAC accepts any key length");
+    mac.update(ciphertext);
+    mac.verify_slice(tag)
+        .map_err(|_| KeystoreError::WrongPassword)?;
+
+    let mut plaintext = ciphertext.clone();
+    ctr::Ctr128BE::<aes::Aes256>::new(cipher_key.into(), iv.into()).apply_keystream(&mut plaintext);
+
+    // the plaintext is PKCS7 padded to the AES block size before encryption
+    let padding = *plaintext.last().ok_or(KeystoreError::CorruptKeyfile)? as usize;
+    if padding == 0
+        || padding > 16
+        || padding > plaintext.len()
+        || !plaintext[plaintext.len() - padding..]
+            .iter()
+            .all(|&b| b as usize == padding)
+    {
+        return Err(KeystoreError::CorruptKeyfile);
+    }
+    plaintext.truncate(plaintext.len() - padding);
+
+    Ok(plaintext)
 }
 
 #[cfg(test)]
@@ -138,14 +271,14 @@ mod test {
         )
         .unwrap();
 
-        let key = secretbox::Key::from_slice(&key_bytes).unwrap();
+        let key = secretbox::Key::from_slice(key_bytes).unwrap();
 
         let nonce = secretbox::Nonce([
             139, 30, 112, 189, 93, 199, 196, 156, 252, 64, 20, 97, 225, 132, 77, 52, 125, 179, 224,
             39, 35, 194, 154, 228,
         ]);
 
-        let encrypted_data = secretbox::seal(&mut cipher.as_bytes(), &nonce, &key);
+        let encrypted_data = secretbox::seal(cipher.as_bytes(), &nonce, &key);
 
         let mut output = "$NACL".as_bytes().to_vec();
         output.extend_from_slice(&nonce.0);
@@ -167,9 +300,9 @@ mod test {
         ]);
 
         let cipher = "244e41434c8b1e70bd5dc7c49cfc401461e1844d347db3e02723c29ae428117ae4a29f30d15425c8d29712be07e313212b";
-        let cipher_bytes = hex::decode(&cipher).unwrap();
+        let cipher_bytes = hex::decode(cipher).unwrap();
 
-        let actual_nonce = extract_nonce_nacl(&cipher_bytes);
+        let actual_nonce = extract_nonce_nacl(&cipher_bytes).unwrap();
 
         assert_eq!(actual_nonce, expected_nonce);
     }
@@ -181,9 +314,9 @@ mod test {
         ];
 
         let cipher = "244e41434c8b1e70bd5dc7c49cfc401461e1844d347db3e02723c29ae428117ae4a29f30d15425c8d29712be07e313212b";
-        let cipher_bytes = hex::decode(&cipher).unwrap();
+        let cipher_bytes = hex::decode(cipher).unwrap();
 
-        let actual_encrypted_data = extract_encrypted_data_nacl(&cipher_bytes);
+        let actual_encrypted_data = extract_encrypted_data_nacl(&cipher_bytes).unwrap();
 
         assert_eq!(actual_encrypted_data, expected_encrypted_data);
     }
@@ -198,8 +331,128 @@ mod test {
         let data = std::fs::read(resource_path).unwrap();
 
         let password = "Password1%";
-        let decrypted = decrypt_nacl(&data, password);
+        let decrypted = decrypt_nacl(&data, password).unwrap();
         let decrypted_str = std::str::from_utf8(&decrypted).unwrap();
         assert_eq!(decrypted_str, expected_output);
     }
+
+    #[test]
+    fn it_detects_encryption_type_from_header() {
+        assert_eq!(
+            EncryptionType::from_header(b"$NACL\x00"),
+            Some(EncryptionType::Nacl)
+        );
+        assert_eq!(
+            EncryptionType::from_header(b"$ANSIBLE_VAULT;1.1;AES256\n"),
+            Some(EncryptionType::Ansible)
+        );
+        assert_eq!(
+            EncryptionType::from_header(b"gAAAAABq1LAN"),
+            Some(EncryptionType::Legacy)
+        );
+        assert_eq!(EncryptionType::from_header(b"{\"accountId\""), None);
+    }
+
+    #[test]
+    fn it_decrypts_fernet_correctly() {
+        let expected_output = r#"{"accountId": "0x8675d3e27ba8a6b6a3cf23668d346ba398b8fd7b7c90f9d72789b21458cd192e", "publicKey": "0x8675d3e27ba8a6b6a3cf23668d346ba398b8fd7b7c90f9d72789b21458cd192e", "secretPhrase": "scheme coin blush private reunion door tuition grid world diagram reopen syrup", "secretSeed": "0x624517f37eabbc2c3d1f23a23de20107f57c2749264b84feac3b0eba5379301e", "ss58Address": "5F71GxBcHF9UfE6uiDcFsgbPNmHJGwPtNfg3F87HEeVczotP"}"#;
+
+        // token made with python's `cryptography.fernet` and the key 0x00..0x1f, skipping the
+        // 10M round PBKDF2 the legacy keyfiles use
+        let token = "gAAAAABq1LANDocZWpDD-eirxDYH0hFGKTA_LN1NHl8p-zgE3X2ufKvMPeAkvU_gjg-p8DYdtZvsZBEZkIp_jDMXibi8Pk_DEShk1exJqbIkFZyyrkcQ7mAK7d-q-NnA2Yh3Tts7OqgcseXTDGHVlXJNyqM_fjhg9FjUlWg9AIAVzeZMOeOUsPDbA_lW2RPMAgKtLtdXptIagzSwPl2rFi2SHj9oEJf3XaZmFhv-EVLTUSbXcVOoCJERKRTGNGcHOr_JaDLTFOOM8YP1v2T7XKETo2U2Wm3OjutzWdxr0L3wgGqhzjJygXovjfluhG-iAP8DkJMaGFKZBPd8sRSNA03I5h-zx4-Y7FN2OGPRj6cWW6yNp2ywHukpzRVqqsfZOXkU0i9t7XQPs-oWEC4viBtrFU7u4wbPpufCgP0HaxWYdgEHbX_kLpQUX49CjnYbGtKGOaZR5_quUlQJHF9B_RRAWYHpP9v8CTpp8FIlDLu3XQSNt5e2YjxG93MgjJTI3A6ODGX0R--Ln4cOw1a03xORHs2Xfu4rAght5HZa-WZ20lTdhB866u1ZxMiqaHSuShWyXeIUP36CkJxZJwUllMh5ryIUXMN0_84V2jL3nV9FEL07Wja1T-s=";
+        let key: [u8; 32] = core::array::from_fn(|i| i as u8);
+
+        let decrypted = decrypt_fernet(token.as_bytes(), &key).unwrap();
+        assert_eq!(std::str::from_utf8(&decrypted).unwrap(), expected_output);
+
+        let wrong_key = [0; 32];
+        assert!(matches!(
+            decrypt_fernet(token.as_bytes(), &wrong_key),
+            Err(KeystoreError::WrongPassword)
+        ));
+    }
+
+    #[test]
+    fn it_derives_legacy_key_correctly() {
+        let expected_output = r#"{"secretSeed": "0x624517f37eabbc2c3d1f23a23de20107f57c2749264b84feac3b0eba5379301e", "ss58Address": "5F71GxBcHF9UfE6uiDcFsgbPNmHJGwPtNfg3F87HEeVczotP"}"#;
+
+        // made with python's `hashlib.pbkdf2_hmac` over the btcli salt and `cryptography.fernet`,
+        // with 1000 rounds so the test does not spend minutes in PBKDF2
+        let token = "gAAAAABq1MslLsIzbrrsfqslxO-7hjX0tK3QSnuXkHX7JYAbgwt-UpAb0Ke_10Bx5U2r0b-qG-rU-dc2jyEmhhYQD2maMD8Nic6xgXJZ87PpHils4ODfPAhIW3xmE2UJA1z3Dj62vrk2kAwgNARk6Rm1JGBptAPFWjxJkyqh5H_jgY1h1Fv6Ap2lDTIID7NmgZtW3ECGRRMxYye3GWQxf6YClOyPO5UyUZnKnMMrRDXBVjpD7AkqWcd7GBJsQzCLWBe1B7UXXSess2xun2QQGjyaLP8CVUpbYw==";
+
+        let key = get_legacy_key("Password1%", 1000);
+        assert_eq!(
+            hex::encode(key),
+            "9e14a46ef6d13ab792f2cd1db61a2f807767932b03486dfab0f15ddbfcb91237"
+        );
+
+        let decrypted = decrypt_fernet(token.as_bytes(), &key).unwrap();
+        assert_eq!(std::str::from_utf8(&decrypted).unwrap(), expected_output);
+    }
+
+    #[test]
+    #[ignore = "10M PBKDF2 rounds take minutes in a debug build"]
+    fn it_decrypts_legacy_correctly() {
+        let expected_output = r#"{"secretSeed": "0x624517f37eabbc2c3d1f23a23de20107f57c2749264b84feac3b0eba5379301e", "ss58Address": "5F71GxBcHF9UfE6uiDcFsgbPNmHJGwPtNfg3F87HEeVczotP"}"#;
+
+        // same as above with the rounds btcli used
+        let token = "gAAAAABq1MqK3m-84Pq49LZi73o8OPCwd8gXdtmqr7s6sG3ZnyJubWIS_oK7setibz557bauVJmeXq61qkBEDwKMjqt9fka0ZDqRyuQKGpIFffC6oXV6GEKpQtO1xE4VgbVyJXTcxgpu58CceOcDBA55UvYQbR1gLTvm-UHJPmMZfjxOrKEdOw4OPx5DpSAPxa3xeHZm9ukKg6WLZ57AH8WbqeU914KSj6v20VHMcFdwm8KKExcNX_zFbeNPblV0AYPb20z28uxcz7C6K_eM95dxQeLQAcZimg==";
+
+        let decrypted = decrypt(token.as_bytes(), "Password1%", EncryptionType::Legacy).unwrap();
+        assert_eq!(std::str::from_utf8(&decrypted).unwrap(), expected_output);
+    }
+
+    #[test]
+    fn it_decrypts_ansible_correctly() {
+        let expected_output = r#"{"accountId": "0x8675d3e27ba8a6b6a3cf23668d346ba398b8fd7b7c90f9d72789b21458cd192e", "publicKey": "0x8675d3e27ba8a6b6a3cf23668d346ba398b8fd7b7c90f9d72789b21458cd192e", "secretPhrase": "scheme coin blush private reunion door tuition grid world diagram reopen syrup", "secretSeed": "0x624517f37eabbc2c3d1f23a23de20107f57c2749264b84feac3b0eba5379301e", "ss58Address": "5F71GxBcHF9UfE6uiDcFsgbPNmHJGwPtNfg3F87HEeVczotP"}"#;
+
+        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+        let resource_path =
+            std::path::Path::new(&manifest_dir).join("resources/testkey_ansible/coldkey");
+
+        let data = std::fs::read(resource_path).unwrap();
+
+        let decrypted = decrypt(&data, "Password1%", EncryptionType::Ansible).unwrap();
+        assert_eq!(std::str::from_utf8(&decrypted).unwrap(), expected_output);
+
+        assert!(matches!(
+            decrypt(&data, "password", EncryptionType::Ansible),
+            Err(KeystoreError::WrongPassword)
+        ));
+    }
+
+    #[test]
+    fn it_rejects_corrupt_nacl_keyfiles() {
+        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+        let resource_path = std::path::Path::new(&manifest_dir).join("resources/testkey/coldkey");
+
+        let data = std::fs::read(resource_path).unwrap();
+
+        // truncated inside the nonce and inside the MAC
+        assert!(matches!(
+            decrypt_nacl(&data[..20], "Password1%"),
+            Err(KeystoreError::CorruptKeyfile)
+        ));
+        assert!(matches!(
+            decrypt_nacl(&data[..5 + secretbox::NONCEBYTES + 8], "Password1%"),
+            Err(KeystoreError::CorruptKeyfile)
+        ));
+        assert!(matches!(
+            decrypt_nacl(b"{\"accountId\": \"0x\"}", "Password1%"),
+            Err(KeystoreError::UnknownFormat)
+        ));
+    }
+
+    #[test]
+    fn it_rejects_wrong_password_nacl() {
+        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+        let resource_path = std::path::Path::new(&manifest_dir).join("resources/testkey/coldkey");
+
+        let data = std::fs::read(resource_path).unwrap();
+
+        assert!(matches!(
+            decrypt_nacl(&data, "Password1"),
+            Err(KeystoreError::WrongPassword)
+        ));
+    }
 }
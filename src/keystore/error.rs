@@ -3,7 +3,7 @@ use thiserror::Error;
 #[derive(Error, Debug)]
 pub enum KeystoreError {
     #[error("Invalid configuration")]
-    Invalid(#[source] figment::Error),
+    Invalid(#[source] Box<figment::Error>),
 
     #[error("IO error")]
     Io(#[from] std::io::Error),
@@ -20,12 +20,30 @@ pub enum KeystoreError {
     #[error("Invalid mnemonic: {0}")]
     InvalidMnemonic(String),
 
+    #[error("Invalid seed: {0}")]
+    InvalidSeed(String),
+
+    #[error("Keyfile holds no secret")]
+    MissingSecret,
+
+    #[error("Wrong password")]
+    WrongPassword,
+
+    #[error("Keyfile is corrupt")]
+    CorruptKeyfile,
+
+    #[error("Unknown keyfile format")]
+    UnknownFormat,
+
     #[error("Unsupported encryption type")]
     UnsupportedEncryptionType,
 
     #[error("No password provided")]
     NoPasswordProvided,
 
+    #[error("No hotkey under the wallet has address {0}")]
+    UnknownHotkey(String),
+
     #[error("Json error")]
     JsonError(#[from] serde_json::Error),
 }
@@ -19,6 +19,7 @@
 pub mod cli;
 pub mod encryption;
 pub mod error;
+pub mod wallet;
 
 use error::KeystoreError;
 
@@ -33,21 +34,31 @@ use sp_core::{
 };
 use sp_runtime::{traits::IdentifyAccount, MultiSigner};
 use std::{fs, os::unix::fs::PermissionsExt};
-use std::{fs::Permissions, path::PathBuf};
+use std::{
+    fs::Permissions,
+    path::{Path, PathBuf},
+};
 
-use self::encryption::EncryptionType;
+pub use self::encryption::EncryptionType;
 
 #[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct Keystore {
+    // the aliases read keyfiles written with the snake_case field names of earlier versions
+    #[serde(alias = "account_id")]
     account_id: String,
+    #[serde(alias = "public_key")]
     public_key: String,
+    #[serde(alias = "secret_phrase")]
     secret_phrase: Option<String>,
+    #[serde(alias = "secret_seed")]
     secret_seed: Option<String>,
+    #[serde(alias = "ss58_address")]
     ss58_address: String,
 
-    #[serde(skip_serializing)]
+    #[serde(skip)]
     name: String,
-    #[serde(skip_serializing)]
+    #[serde(skip)]
     password: Option<String>,
 }
 
@@ -87,38 +98,155 @@ impl Keystore {
         }
     }
 
+    /// builds a keystore from a hex encoded `secretSeed`, the phrase is not recoverable
+    pub fn new_from_seed<Pair>(
+        name: &str,
+        seed: &str,
+        password: Option<SecretString>,
+        network_override: Option<Ss58AddressFormat>,
+    ) -> Result<Self, KeystoreError>
+    where
+        Pair: sp_core::Pair,
+        Pair::Public: Into<MultiSigner>,
+    {
+        let seed_bytes = hex::decode(seed.trim().trim_start_matches("0x"))
+            .map_err(|e| KeystoreError::InvalidSeed(format!("Seed is not valid hex: {e}")))?;
+        // only a 32 byte mini secret can be turned back into a signer, see `to_keypair`
+        if seed_bytes.len() != 32 {
+            return Err(KeystoreError::InvalidSeed(format!(
+                "Seed must be 32 bytes, got {}",
+                seed_bytes.len()
+            )));
+        }
+
+        let pair = Pair::from_seed_slice(&seed_bytes).map_err(|_| {
+            KeystoreError::InvalidSeed("Invalid seed while creating keyfile".into())
+        })?;
+
+        let public_key = pair.public();
+        let network_override = unwrap_or_default_ss58_version(network_override);
+        let ss58_address = public_key.to_ss58check_with_version(network_override);
+
+        Ok(Keystore {
+            secret_phrase: None,
+            secret_seed: Some(format!("0x{}", HexDisplay::from(&seed_bytes))),
+            public_key: format_public_key::<Pair>(public_key.clone()),
+            account_id: format_account_id::<Pair>(public_key),
+            ss58_address,
+
+            name: name.to_string(),
+            password: password
+                .as_ref()
+                .map(|s| s.expose_secret().as_str())
+                .map(|s| s.to_string()),
+        })
+    }
+
+    /// builds a keystore holding only the public half of a key, used for watch-only coldkeys
+    pub fn new_from_public<Pair>(
+        name: &str,
+        public_key: PublicFor<Pair>,
+        network_override: Option<Ss58AddressFormat>,
+    ) -> Self
+    where
+        Pair: sp_core::Pair,
+        Pair::Public: Into<MultiSigner>,
+    {
+        let network_override = unwrap_or_default_ss58_version(network_override);
+        let ss58_address = public_key.to_ss58check_with_version(network_override);
+
+        Keystore {
+            secret_phrase: None,
+            secret_seed: None,
+            public_key: format_public_key::<Pair>(public_key.clone()),
+            account_id: format_account_id::<Pair>(public_key),
+            ss58_address,
+
+            name: name.to_string(),
+            password: None,
+        }
+    }
+
     pub fn new_from_disk(
-        path: &PathBuf,
+        path: &Path,
         password: Option<SecretString>,
     ) -> Result<Self, KeystoreError> {
         let encrypted = fs::read(path.join("coldkey"))
-            .map_err(|e| KeystoreError::Io(e))?
+            .map_err(KeystoreError::Io)?
             .to_vec();
 
         let password = password
             .as_ref()
             .map(|s| s.expose_secret().as_str())
             .map(|s| s.to_string())
-            .unwrap();
+            .ok_or(KeystoreError::NoPasswordProvided)?;
 
-        let decrypted = encryption::decrypt(&encrypted, &password, EncryptionType::Nacl)?;
+        let encryption_type =
+            EncryptionType::from_header(&encrypted).ok_or(KeystoreError::UnknownFormat)?;
+        let decrypted = encryption::decrypt(&encrypted, &password, encryption_type)?;
 
         let decrypted_str =
-            std::str::from_utf8(&decrypted).map_err(|_| KeystoreError::NoPasswordProvided)?;
+            std::str::from_utf8(&decrypted).map_err(|_| KeystoreError::CorruptKeyfile)?;
+
+        let mut keystore: Keystore =
+            serde_json::from_str(decrypted_str).map_err(KeystoreError::JsonError)?;
+
+        // keep what is needed to write the keyfile back, e.g. when re-encrypting it
+        keystore.name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        keystore.password = Some(password);
+
+        Ok(keystore)
+    }
+
+    /// reads an unencrypted keyfile such as `coldkeypub.txt` or a hotkey, nothing is decrypted
+    pub fn new_from_unencrypted_file(path: &Path, name: &str) -> Result<Self, KeystoreError> {
+        let contents = fs::read_to_string(path).map_err(KeystoreError::Io)?;
 
-        let keystore: Keystore =
-            serde_json::from_str(&decrypted_str).map_err(|e| KeystoreError::JsonError(e))?;
+        let mut keystore: Keystore =
+            serde_json::from_str(&contents).map_err(KeystoreError::JsonError)?;
+        keystore.name = name.to_string();
 
         Ok(keystore)
     }
 
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn ss58_address(&self) -> &str {
+        &self.ss58_address
+    }
+
+    pub fn password(&self) -> Option<SecretString> {
+        self.password.clone().map(SecretString::new)
+    }
+
+    /// sr25519 signer for submitting extrinsics, rebuilt from the stored `secretSeed`
+    pub fn to_keypair(&self) -> Result<subxt_signer::sr25519::Keypair, KeystoreError> {
+        let seed = self
+            .secret_seed
+            .as_ref()
+            .ok_or(KeystoreError::MissingSecret)?;
+
+        let seed: [u8; 32] = hex::decode(seed.trim_start_matches("0x"))
+            .map_err(|e| KeystoreError::InvalidSeed(format!("Seed is not valid hex: {e}")))?
+            .try_into()
+            .map_err(|_| KeystoreError::InvalidSeed("Seed must be 32 bytes".into()))?;
+
+        subxt_signer::sr25519::Keypair::from_seed(seed)
+            .map_err(|e| KeystoreError::InvalidSeed(e.to_string()))
+    }
+
     pub fn to_json(&self) -> Result<serde_json::Value, KeystoreError> {
         Ok(json!(self))
     }
 
     pub fn save_unencrypted_without_secrets_to_file(
         &self,
-        path: &PathBuf,
+        path: &Path,
     ) -> Result<(), KeystoreError> {
         let json = serde_json::json!({
             "accountId": self.account_id,
@@ -131,22 +259,20 @@ impl Keystore {
         .to_string();
 
         let full_path = path.join("coldkeypub.txt");
-        fs::write(&full_path, json).map_err(KeystoreError::Io);
-        fs::set_permissions(&path, Permissions::from_mode(0o600)).map_err(KeystoreError::Io)?;
+        fs::write(&full_path, json).map_err(KeystoreError::Io)?;
+        fs::set_permissions(&full_path, Permissions::from_mode(0o600))
+            .map_err(KeystoreError::Io)?;
         Ok(())
     }
 
-    pub fn save_unencrypted_with_secrets_to_file(
-        &self,
-        path: &PathBuf,
-    ) -> Result<(), KeystoreError> {
+    pub fn save_unencrypted_with_secrets_to_file(&self, path: &Path) -> Result<(), KeystoreError> {
         let json = self.to_json()?.to_string();
-        fs::write(path, json).map_err(|e| KeystoreError::Io(e));
-        fs::set_permissions(&path, Permissions::from_mode(0o600)).map_err(KeystoreError::Io)?;
+        fs::write(path, json).map_err(KeystoreError::Io)?;
+        fs::set_permissions(path, Permissions::from_mode(0o600)).map_err(KeystoreError::Io)?;
         Ok(())
     }
 
-    pub fn save_encrypted_with_secrets_to_file(&self, path: &PathBuf) -> Result<(), KeystoreError> {
+    pub fn save_encrypted_with_secrets_to_file(&self, path: &Path) -> Result<(), KeystoreError> {
         let json = self.to_json()?.to_string();
         if self.password.is_none() {
             return Err(KeystoreError::NoPasswordProvided);
@@ -157,25 +283,34 @@ impl Keystore {
 
         let full_path = path.join("coldkey");
 
-        fs::write(&full_path, encrypted).map_err(|e| KeystoreError::Io(e))?;
+        fs::write(&full_path, encrypted).map_err(KeystoreError::Io)?;
         fs::set_permissions(&full_path, Permissions::from_mode(0o600))
             .map_err(KeystoreError::Io)?;
         Ok(())
     }
 }
 
-pub fn create_keyfile_directory(path: &PathBuf) -> Result<(), KeystoreError> {
+pub fn create_keyfile_directory(path: &Path) -> Result<(), KeystoreError> {
     fs::create_dir_all(path).map_err(KeystoreError::Io)
 }
 
+/// encryption of the coldkey stored under `path`, `None` if the header is not recognised
+pub fn coldkey_encryption_type(path: &Path) -> Result<Option<EncryptionType>, KeystoreError> {
+    let encrypted = fs::read(path.join("coldkey")).map_err(KeystoreError::Io)?;
+    Ok(EncryptionType::from_header(&encrypted))
+}
+
+/// path of the hotkey `name` stored under the coldkey `wallet`, as laid out by btcli
+pub fn hotkey_path(key_path: &Path, wallet: &str, name: &str) -> PathBuf {
+    key_path.join(wallet).join("hotkeys").join(name)
+}
+
 pub fn validate_wordcount(num_words: Option<usize>) -> Result<usize, KeystoreError> {
-    let words = match num_words {
+    match num_words {
         Some(words_count) if [12, 15, 18, 21, 24].contains(&words_count) => Ok(words_count),
         Some(_) => Err(KeystoreError::WordCount),
         None => Ok(12),
-    };
-
-    return words;
+    }
 }
 
 pub fn validate_password(password: &str) -> bool {
@@ -185,7 +320,7 @@ pub fn validate_password(password: &str) -> bool {
         return false;
     }
 
-    return true;
+    true
 }
 
 /// formats seed as hex
@@ -215,8 +350,8 @@ mod tests {
 
     #[test]
     fn test_validate_password() {
-        assert_eq!(validate_password("passwor"), false);
-        assert_eq!(validate_password("password123"), true);
+        assert!(!validate_password("passwor"));
+        assert!(validate_password("password123"));
     }
 
     #[test]
@@ -229,4 +364,158 @@ mod tests {
         assert!(validate_wordcount(Some(25)).is_err());
         assert_eq!(validate_wordcount(None).unwrap(), 12);
     }
+
+    #[test]
+    fn test_hotkey_path() {
+        let path = hotkey_path(Path::new("/tmp/wallets"), "default", "miner1");
+        assert_eq!(path, PathBuf::from("/tmp/wallets/default/hotkeys/miner1"));
+    }
+
+    #[test]
+    fn test_new_from_seed() {
+        let seed = "0x624517f37eabbc2c3d1f23a23de20107f57c2749264b84feac3b0eba5379301e";
+        let keystore = Keystore::new_from_seed::<sp_core::sr25519::Pair>(
+            "test",
+            seed,
+            None,
+            Some(Ss58AddressFormat::custom(42)),
+        )
+        .unwrap();
+
+        assert_eq!(
+            keystore.ss58_address(),
+            "5F71GxBcHF9UfE6uiDcFsgbPNmHJGwPtNfg3F87HEeVczotP"
+        );
+        assert!(
+            Keystore::new_from_seed::<sp_core::sr25519::Pair>("test", "0xnothex", None, None)
+                .is_err()
+        );
+
+        // a 64 byte secret key could be stored but never signed with
+        let secret_key = format!("0x{}", "11".repeat(64));
+        assert!(matches!(
+            Keystore::new_from_seed::<sp_core::sr25519::Pair>("test", &secret_key, None, None),
+            Err(KeystoreError::InvalidSeed(_))
+        ));
+    }
+
+    #[test]
+    fn test_new_from_public() {
+        let public_key = sp_core::sr25519::Public::from_ss58check(
+            "5F71GxBcHF9UfE6uiDcFsgbPNmHJGwPtNfg3F87HEeVczotP",
+        )
+        .unwrap();
+        let keystore = Keystore::new_from_public::<sp_core::sr25519::Pair>(
+            "test",
+            public_key,
+            Some(Ss58AddressFormat::custom(42)),
+        );
+
+        let json = keystore.to_json().unwrap();
+        assert_eq!(
+            json["publicKey"],
+            "0x8675d3e27ba8a6b6a3cf23668d346ba398b8fd7b7c90f9d72789b21458cd192e"
+        );
+        assert!(json["secretPhrase"].is_null());
+        assert!(json["secretSeed"].is_null());
+    }
+
+    #[test]
+    fn test_to_keypair() {
+        let phrase =
+            "scheme coin blush private reunion door tuition grid world diagram reopen syrup";
+        let keystore = Keystore::new::<sp_core::sr25519::Pair>("test", phrase, None, None).unwrap();
+
+        let keypair = keystore.to_keypair().unwrap();
+        assert_eq!(
+            format!("0x{}", hex::encode(keypair.public_key().0)),
+            "0x8675d3e27ba8a6b6a3cf23668d346ba398b8fd7b7c90f9d72789b21458cd192e"
+        );
+
+        let public_key = sp_core::sr25519::Public::from_ss58check(
+            "5F71GxBcHF9UfE6uiDcFsgbPNmHJGwPtNfg3F87HEeVczotP",
+        )
+        .unwrap();
+        let watch_only =
+            Keystore::new_from_public::<sp_core::sr25519::Pair>("test", public_key, None);
+        assert!(watch_only.to_keypair().is_err());
+    }
+
+    #[test]
+    fn test_keyfile_json_matches_btcli() {
+        let phrase =
+            "scheme coin blush private reunion door tuition grid world diagram reopen syrup";
+        let keystore = Keystore::new::<sp_core::sr25519::Pair>(
+            "test",
+            phrase,
+            None,
+            Some(Ss58AddressFormat::custom(42)),
+        )
+        .unwrap();
+
+        let json = keystore.to_json().unwrap();
+        assert_eq!(
+            json["ss58Address"],
+            "5F71GxBcHF9UfE6uiDcFsgbPNmHJGwPtNfg3F87HEeVczotP"
+        );
+        assert_eq!(json["secretPhrase"], phrase);
+        assert!(json.get("name").is_none());
+        assert!(json.get("password").is_none());
+    }
+
+    #[test]
+    fn test_reads_snake_case_keyfile() {
+        // as written by create-coldkey before the keyfile matched btcli
+        let json = r#"{"account_id":"0x8675d3e27ba8a6b6a3cf23668d346ba398b8fd7b7c90f9d72789b21458cd192e","public_key":"0x8675d3e27ba8a6b6a3cf23668d346ba398b8fd7b7c90f9d72789b21458cd192e","secret_phrase":"scheme coin blush private reunion door tuition grid world diagram reopen syrup","secret_seed":"0x624517f37eabbc2c3d1f23a23de20107f57c2749264b84feac3b0eba5379301e","ss58_address":"5F71GxBcHF9UfE6uiDcFsgbPNmHJGwPtNfg3F87HEeVczotP"}"#;
+
+        let keystore: Keystore = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            keystore.ss58_address(),
+            "5F71GxBcHF9UfE6uiDcFsgbPNmHJGwPtNfg3F87HEeVczotP"
+        );
+        assert!(keystore.to_keypair().is_ok());
+
+        // written back in the btcli layout
+        let json = keystore.to_json().unwrap();
+        assert_eq!(
+            json["accountId"],
+            "0x8675d3e27ba8a6b6a3cf23668d346ba398b8fd7b7c90f9d72789b21458cd192e"
+        );
+        assert!(json.get("account_id").is_none());
+    }
+
+    #[test]
+    fn test_new_from_disk() {
+        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+        let fixture = Path::new(&manifest_dir).join("resources/testkey");
+        let password = || Some(SecretString::new("Password1%".into()));
+
+        let keystore = Keystore::new_from_disk(&fixture, password()).unwrap();
+        assert_eq!(
+            keystore.ss58_address(),
+            "5F71GxBcHF9UfE6uiDcFsgbPNmHJGwPtNfg3F87HEeVczotP"
+        );
+        assert_eq!(keystore.name(), "testkey");
+
+        assert!(matches!(
+            Keystore::new_from_disk(&fixture, None),
+            Err(KeystoreError::NoPasswordProvided)
+        ));
+        assert!(matches!(
+            Keystore::new_from_disk(&fixture, Some(SecretString::new("Password1".into()))),
+            Err(KeystoreError::WrongPassword)
+        ));
+        assert!(matches!(
+            Keystore::new_from_disk(&fixture.join("missing"), password()),
+            Err(KeystoreError::Io(_))
+        ));
+
+        // an unencrypted keyfile is not something btcli would write as a coldkey
+        let dir = std::env::temp_dir().join(format!("tensors-test-{}", std::process::id()));
+        create_keyfile_directory(&dir).unwrap();
+        fs::copy(fixture.join("coldkeypub.txt"), dir.join("coldkey")).unwrap();
+        let result = Keystore::new_from_disk(&dir, password());
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(matches!(result, Err(KeystoreError::UnknownFormat)));
+    }
 }
@@ -0,0 +1,177 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use subxt::utils::AccountId32;
+
+use super::{error::KeystoreError, hotkey_path, Keystore};
+
+/// hotkey file found under a wallet, the address is `None` if the file could not be read
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hotkey {
+    pub name: String,
+    pub ss58_address: Option<String>,
+}
+
+/// public view of a wallet directory, built without decrypting anything
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wallet {
+    pub name: String,
+    pub path: PathBuf,
+    pub coldkey_ss58: Option<String>,
+    pub hotkeys: Vec<Hotkey>,
+}
+
+/// every wallet under `key_path`, sorted by name
+pub fn list_wallets(key_path: &Path) -> Result<Vec<Wallet>, KeystoreError> {
+    let mut wallets = Vec::new();
+
+    for entry in fs::read_dir(key_path).map_err(KeystoreError::Io)? {
+        let entry = entry.map_err(KeystoreError::Io)?;
+        if !entry.file_type().map_err(KeystoreError::Io)?.is_dir() {
+            continue;
+        }
+
+        let name = entry.file_name().to_string_lossy().into_owned();
+        wallets.push(read_wallet(key_path, &name)?);
+    }
+
+    wallets.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(wallets)
+}
+
+/// reads `coldkeypub.txt` and the hotkeys of the wallet `name`
+pub fn read_wallet(key_path: &Path, name: &str) -> Result<Wallet, KeystoreError> {
+    let path = key_path.join(name);
+
+    let coldkeypub = path.join("coldkeypub.txt");
+    let coldkey_ss58 = Keystore::new_from_unencrypted_file(&coldkeypub, name)
+        .ok()
+        .map(|keystore| keystore.ss58_address().to_string());
+
+    let mut hotkeys = Vec::new();
+    let hotkeys_path = path.join("hotkeys");
+    if hotkeys_path.is_dir() {
+        for entry in fs::read_dir(&hotkeys_path).map_err(KeystoreError::Io)? {
+            let entry = entry.map_err(KeystoreError::Io)?;
+            if !entry.file_type().map_err(KeystoreError::Io)?.is_file() {
+                continue;
+            }
+
+            let hotkey_name = entry.file_name().to_string_lossy().into_owned();
+            let ss58_address = Keystore::new_from_unencrypted_file(&entry.path(), &hotkey_name)
+                .ok()
+                .map(|keystore| keystore.ss58_address().to_string());
+
+            hotkeys.push(Hotkey {
+                name: hotkey_name,
+                ss58_address,
+            });
+        }
+    }
+    hotkeys.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(Wallet {
+        name: name.to_string(),
+        path,
+        coldkey_ss58,
+        hotkeys,
+    })
+}
+
+/// hotkey keyfile `name` under `wallet`, holding the secret needed to sign with it
+pub fn read_hotkey(key_path: &Path, wallet: &str, name: &str) -> Result<Keystore, KeystoreError> {
+    Keystore::new_from_unencrypted_file(&hotkey_path(key_path, wallet, name), name)
+}
+
+/// coldkey account of `wallet`, read from `coldkeypub.txt` so no password is needed
+pub fn coldkey_account_id(key_path: &Path, wallet: &str) -> Result<AccountId32, KeystoreError> {
+    let keystore =
+        Keystore::new_from_unencrypted_file(&key_path.join(wallet).join("coldkeypub.txt"), wallet)?;
+    AccountId32::from_str(keystore.ss58_address()).map_err(|_| KeystoreError::CorruptKeyfile)
+}
+
+/// account of the hotkey `hotkey` stored under `wallet`
+pub fn hotkey_account_id(
+    key_path: &Path,
+    wallet: &str,
+    hotkey: &str,
+) -> Result<AccountId32, KeystoreError> {
+    let keystore = read_hotkey(key_path, wallet, hotkey)?;
+    AccountId32::from_str(keystore.ss58_address()).map_err(|_| KeystoreError::CorruptKeyfile)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_wallets() {
+        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+        let coldkeypub = Path::new(&manifest_dir).join("resources/testkey/coldkeypub.txt");
+        let address = "5F71GxBcHF9UfE6uiDcFsgbPNmHJGwPtNfg3F87HEeVczotP";
+
+        let key_path = std::env::temp_dir().join(format!("tensors-wallets-{}", std::process::id()));
+        fs::create_dir_all(key_path.join("b/hotkeys")).unwrap();
+        fs::create_dir_all(key_path.join("a")).unwrap();
+        fs::copy(&coldkeypub, key_path.join("b/coldkeypub.txt")).unwrap();
+        fs::copy(&coldkeypub, key_path.join("b/hotkeys/miner2")).unwrap();
+        fs::write(key_path.join("b/hotkeys/miner1"), "not a keyfile").unwrap();
+        fs::write(key_path.join("stray.txt"), "").unwrap();
+
+        let wallets = list_wallets(&key_path);
+        fs::remove_dir_all(&key_path).unwrap();
+        let wallets = wallets.unwrap();
+
+        assert_eq!(wallets.len(), 2);
+        assert_eq!(wallets[0].name, "a");
+        assert_eq!(wallets[0].coldkey_ss58, None);
+        assert!(wallets[0].hotkeys.is_empty());
+
+        assert_eq!(wallets[1].name, "b");
+        assert_eq!(wallets[1].coldkey_ss58.as_deref(), Some(address));
+        assert_eq!(
+            wallets[1].hotkeys,
+            vec![
+                Hotkey {
+                    name: "miner1".into(),
+                    ss58_address: None,
+                },
+                Hotkey {
+                    name: "miner2".into(),
+                    ss58_address: Some(address.into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hotkey_account_id() {
+        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+        let address = "5F71GxBcHF9UfE6uiDcFsgbPNmHJGwPtNfg3F87HEeVczotP";
+        let expected = AccountId32::from_str(address).unwrap();
+
+        // the fixture's coldkeypub.txt has the same layout as a hotkey file
+        let key_path = std::env::temp_dir().join(format!("tensors-hotkey-{}", std::process::id()));
+        fs::create_dir_all(key_path.join("default/hotkeys")).unwrap();
+        fs::copy(
+            Path::new(&manifest_dir).join("resources/testkey/coldkeypub.txt"),
+            key_path.join("default/hotkeys/miner1"),
+        )
+        .unwrap();
+
+        let from_file = hotkey_account_id(&key_path, "default", "miner1");
+        let missing = hotkey_account_id(&key_path, "default", "miner2");
+        let coldkey = coldkey_account_id(&key_path, "default");
+        fs::remove_dir_all(&key_path).unwrap();
+
+        assert_eq!(from_file.unwrap(), expected);
+        assert!(matches!(missing, Err(KeystoreError::Io(_))));
+        assert!(matches!(coldkey, Err(KeystoreError::Io(_))));
+
+        let resources = Path::new(&manifest_dir).join("resources");
+        assert_eq!(coldkey_account_id(&resources, "testkey").unwrap(), expected);
+    }
+}
@@ -1,10 +1,40 @@
 use clap::{crate_version, Parser, Subcommand};
-use std::{env, process};
+use std::{env, error::Error, process};
 use tensors::{
     commands::{
+        address::{convert::convert, AddressCommands},
+        axon::{serve::serve_axon, show::show_axon, AxonCommands},
         create_coldkey::{create_new_coldkey, CreateColdkeyArgs},
+        delegate::{
+            become_delegate::become_delegate,
+            list::list_delegates,
+            nominate::{nominate, undelegate},
+            DelegateCommands,
+        },
+        metagraph::{
+            diff::metagraph_diff,
+            show::{metagraph, MetagraphArgs},
+            MetagraphCommands,
+        },
+        new_hotkey::{create_new_hotkey, NewHotkeyArgs},
+        prometheus::{serve::serve_prometheus, PrometheusCommands},
+        regen_coldkey::{regen_coldkey, RegenColdkeyArgs},
+        regen_coldkeypub::{regen_coldkeypub, RegenColdkeypubArgs},
+        root::{list::root_list, register::root_register, weights::root_weights, RootCommands},
+        stake::{add_stake, remove_stake, StakeCommands},
+        subnet::{
+            burned_register::burned_register, hyperparameters::hyperparameters, list::subnet_list,
+            register::register, SubnetCommands,
+        },
         transfer::transfer,
         transfer::TransferArgs,
+        tx::{build::build_tx, sign::sign_tx, submit::submit_tx, TxCommands},
+        update_coldkey::{update_coldkey, UpdateColdkeyArgs},
+        wallet::{
+            balance::balance, list::list_wallets, overview::overview, swap_hotkey::swap_hotkey,
+            WalletCommands,
+        },
+        weights::{set::set_weights, WeightsCommands},
         CliArgs,
     },
     config::Config,
@@ -23,13 +53,56 @@ struct App {
 
 #[derive(Debug, Subcommand)]
 enum Commands {
-    Stake,
+    #[command(subcommand)]
+    Stake(StakeCommands),
 
     #[command(arg_required_else_help = true)]
     Transfer(TransferArgs),
 
     #[command(arg_required_else_help = true)]
     CreateColdkey(CreateColdkeyArgs),
+
+    #[command(arg_required_else_help = true)]
+    NewHotkey(NewHotkeyArgs),
+
+    // without flags the mnemonic or seed is prompted for
+    RegenColdkey(RegenColdkeyArgs),
+
+    #[command(arg_required_else_help = true)]
+    RegenColdkeypub(RegenColdkeypubArgs),
+
+    #[command(arg_required_else_help = true)]
+    UpdateColdkey(UpdateColdkeyArgs),
+
+    #[command(subcommand)]
+    Wallet(WalletCommands),
+
+    #[command(subcommand)]
+    Subnet(SubnetCommands),
+
+    #[command(subcommand)]
+    Root(RootCommands),
+
+    #[command(subcommand)]
+    Weights(WeightsCommands),
+
+    #[command(subcommand)]
+    Axon(AxonCommands),
+
+    #[command(subcommand)]
+    Prometheus(PrometheusCommands),
+
+    #[command(subcommand)]
+    Delegate(DelegateCommands),
+
+    #[command(subcommand)]
+    Address(AddressCommands),
+
+    #[command(subcommand)]
+    Tx(TxCommands),
+
+    #[command(arg_required_else_help = true)]
+    Metagraph(MetagraphArgs),
 }
 
 #[tokio::main]
@@ -44,30 +117,194 @@ async fn main() {
         config.reload_from_path().unwrap();
     }
 
-    println!("Config: {:?}", config);
+    // stderr, so output like `metagraph --format json` can be piped
+    eprintln!("Config: {:?}", config);
 
     // handle commands
-    match &args.command {
-        Some(Commands::Stake) => {
-            println!("Staking with config: {:?}", config);
-        }
+    let result = match &args.command {
+        Some(Commands::Stake(stake_command)) => match stake_command {
+            StakeCommands::Add(stake_args) => {
+                println!("Adding stake");
+                add_stake(&config, stake_args).await
+            }
+            StakeCommands::Remove(stake_args) => {
+                println!("Removing stake");
+                remove_stake(&config, stake_args).await
+            }
+        },
 
         Some(Commands::Transfer(transfer_args)) => {
             println!("Transfering with config: {:?}", config);
             println!("Transfering with args: {:?}", transfer_args);
             config.merge_with_transfer_args(transfer_args);
 
-            transfer(&config, transfer_args).await.unwrap();
+            transfer(&config, transfer_args).await
         }
 
         Some(Commands::CreateColdkey(create_coldkey_args)) => {
             println!("Creating coldkey");
-            create_new_coldkey(&config, &create_coldkey_args).unwrap();
+            create_new_coldkey(&config, create_coldkey_args)
         }
 
+        Some(Commands::NewHotkey(new_hotkey_args)) => {
+            println!("Creating hotkey");
+            create_new_hotkey(&config, new_hotkey_args)
+        }
+
+        Some(Commands::RegenColdkey(regen_coldkey_args)) => {
+            println!("Regenerating coldkey");
+            regen_coldkey(&config, regen_coldkey_args)
+        }
+
+        Some(Commands::RegenColdkeypub(regen_coldkeypub_args)) => {
+            println!("Regenerating coldkeypub");
+            regen_coldkeypub(&config, regen_coldkeypub_args)
+        }
+
+        Some(Commands::UpdateColdkey(update_coldkey_args)) => {
+            println!("Updating coldkey encryption");
+            update_coldkey(&config, update_coldkey_args)
+        }
+
+        Some(Commands::Wallet(wallet_command)) => match wallet_command {
+            WalletCommands::List => {
+                list_wallets(&config)
+            }
+            WalletCommands::Overview(overview_args) => {
+                overview(&config, overview_args).await
+            }
+            WalletCommands::Balance(balance_args) => {
+                balance(&config, balance_args).await
+            }
+            WalletCommands::SwapHotkey(swap_hotkey_args) => {
+                println!("Swapping hotkey");
+                swap_hotkey(&config, swap_hotkey_args).await
+            }
+        },
+
+        Some(Commands::Subnet(subnet_command)) => match subnet_command {
+            SubnetCommands::Register(register_args) => {
+                println!("Registering hotkey");
+                register(&config, register_args).await
+            }
+            SubnetCommands::BurnedRegister(burned_register_args) => {
+                println!("Registering hotkey with burn");
+                burned_register(&config, burned_register_args)
+                    .await
+            }
+            SubnetCommands::List(subnet_list_args) => {
+                subnet_list(&config, subnet_list_args).await
+            }
+            SubnetCommands::Hyperparameters(hyperparameters_args) => {
+                hyperparameters(&config, hyperparameters_args)
+                    .await
+            }
+        },
+
+        Some(Commands::Root(root_command)) => match root_command {
+            RootCommands::Register(root_register_args) => {
+                println!("Registering on the root network");
+                root_register(&config, root_register_args).await
+            }
+            RootCommands::Weights(root_weights_args) => {
+                println!("Setting root weights");
+                root_weights(&config, root_weights_args).await
+            }
+            RootCommands::List(root_list_args) => {
+                root_list(&config, root_list_args).await
+            }
+        },
+
+        Some(Commands::Weights(weights_command)) => match weights_command {
+            WeightsCommands::Set(set_weights_args) => {
+                println!("Setting weights");
+                set_weights(&config, set_weights_args).await
+            }
+        },
+
+        Some(Commands::Axon(axon_command)) => match axon_command {
+            AxonCommands::Serve(serve_axon_args) => {
+                println!("Serving axon");
+                serve_axon(&config, serve_axon_args).await
+            }
+            AxonCommands::Show(show_axon_args) => {
+                show_axon(&config, show_axon_args).await
+            }
+        },
+
+        Some(Commands::Prometheus(prometheus_command)) => match prometheus_command {
+            PrometheusCommands::Serve(serve_prometheus_args) => {
+                println!("Serving prometheus");
+                serve_prometheus(&config, serve_prometheus_args)
+                    .await
+            }
+        },
+
+        Some(Commands::Delegate(delegate_command)) => match delegate_command {
+            DelegateCommands::Become(become_delegate_args) => {
+                println!("Becoming a delegate");
+                become_delegate(&config, become_delegate_args)
+                    .await
+            }
+            DelegateCommands::List(list_delegates_args) => {
+                list_delegates(&config, list_delegates_args).await
+            }
+            DelegateCommands::Nominate(stake_args) => {
+                println!("Nominating delegate");
+                nominate(&config, stake_args).await
+            }
+            DelegateCommands::Undelegate(stake_args) => {
+                println!("Undelegating");
+                undelegate(&config, stake_args).await
+            }
+        },
+
+        Some(Commands::Address(address_command)) => match address_command {
+            AddressCommands::Convert(convert_args) => {
+                convert(&config, convert_args)
+            }
+        },
+
+        Some(Commands::Tx(tx_command)) => match tx_command {
+            TxCommands::Build(build_tx_args) => {
+                println!("Building unsigned transaction");
+                build_tx(&config, build_tx_args).await
+            }
+            TxCommands::Sign(sign_tx_args) => {
+                println!("Signing transaction offline");
+                sign_tx(&config, sign_tx_args)
+            }
+            TxCommands::Submit(submit_tx_args) => {
+                println!("Submitting signed transaction");
+                submit_tx(&config, submit_tx_args).await
+            }
+        },
+
+        Some(Commands::Metagraph(metagraph_args)) => match &metagraph_args.command {
+            Some(MetagraphCommands::Diff(diff_args)) => {
+                metagraph_diff(&config, diff_args).await
+            }
+            None => {
+                metagraph(&config, metagraph_args).await
+            }
+        },
+
         None => {
             eprintln!("No command provided");
             process::exit(1);
         }
+    };
+
+    if let Err(e) = result {
+        // the variants only name the kind of error, their sources say what went wrong
+        let mut message = e.to_string();
+        let mut source = e.source();
+        while let Some(cause) = source {
+            message.push_str(&format!(": {cause}"));
+            source = cause.source();
+        }
+
+        eprintln!("Error: {message}");
+        process::exit(1);
     }
 }
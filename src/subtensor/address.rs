@@ -0,0 +1,197 @@
+use std::{fmt, path::Path, str::FromStr};
+
+use sp_core::crypto::{AccountId32 as SpAccountId32, Ss58AddressFormat, Ss58Codec};
+use subxt::utils::AccountId32;
+
+use crate::keystore::{error::KeystoreError, wallet, Keystore};
+
+/// SS58 prefix of the addresses `Keystore::new` generates
+pub const SS58_PREFIX: u16 = 42;
+
+/// an account given as an SS58 address, a 0x hex public key, or a wallet or hotkey name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Address {
+    Account(AccountId32),
+    Name(String),
+}
+
+impl Address {
+    /// resolves a name as a coldkey wallet under `key_path`
+    pub fn coldkey_account_id(&self, key_path: &Path) -> Result<AccountId32, KeystoreError> {
+        match self {
+            Address::Account(account) => Ok(account.clone()),
+            Address::Name(name) => wallet::coldkey_account_id(key_path, name),
+        }
+    }
+
+    /// resolves a name as a hotkey stored under `wallet`
+    pub fn hotkey_account_id(
+        &self,
+        key_path: &Path,
+        wallet: &str,
+    ) -> Result<AccountId32, KeystoreError> {
+        match self {
+            Address::Account(account) => Ok(account.clone()),
+            Address::Name(name) => wallet::hotkey_account_id(key_path, wallet, name),
+        }
+    }
+
+    /// hotkey keyfile under `wallet` to sign with, an address is looked up among its hotkeys
+    pub fn hotkey_keystore(
+        &self,
+        key_path: &Path,
+        wallet: &str,
+    ) -> Result<Keystore, KeystoreError> {
+        let name = match self {
+            Address::Name(name) => name.clone(),
+            Address::Account(account) => {
+                let address = encode_ss58(account, SS58_PREFIX);
+                wallet::read_wallet(key_path, wallet)?
+                    .hotkeys
+                    .into_iter()
+                    .find(|hotkey| hotkey.ss58_address.as_ref() == Some(&address))
+                    .map(|hotkey| hotkey.name)
+                    .ok_or(KeystoreError::UnknownHotkey(address))?
+            }
+        };
+
+        wallet::read_hotkey(key_path, wallet, &name)
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Address::Account(account) => write!(f, "{account}"),
+            Address::Name(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+impl FromStr for Address {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if s.starts_with("0x") {
+            return parse_public_key(s).map(Address::Account);
+        }
+
+        // anything that does not decode as SS58 is taken as a name
+        if let Ok((account, prefix)) = decode_ss58(s) {
+            if prefix != SS58_PREFIX {
+                return Err(format!(
+                    "Address {s} uses SS58 prefix {prefix}, expected {SS58_PREFIX}"
+                ));
+            }
+            return Ok(Address::Account(account));
+        }
+
+        if s.is_empty() || s.contains(['/', '\\']) || s.starts_with('.') {
+            return Err(format!("Invalid wallet or hotkey name: {s}"));
+        }
+
+        Ok(Address::Name(s.to_string()))
+    }
+}
+
+/// decodes an SS58 address of any prefix, checking its checksum
+pub fn decode_ss58(s: &str) -> Result<(AccountId32, u16), String> {
+    let (account, format) = SpAccountId32::from_ss58check_with_version(s)
+        .map_err(|e| format!("Invalid SS58 address {s}: {e}"))?;
+
+    Ok((AccountId32(account.into()), format.prefix()))
+}
+
+/// encodes `account` as SS58 with the given prefix
+pub fn encode_ss58(account: &AccountId32, prefix: u16) -> String {
+    SpAccountId32::from(account.0).to_ss58check_with_version(Ss58AddressFormat::custom(prefix))
+}
+
+/// parses a 0x prefixed, 32 byte hex public key
+pub fn parse_public_key(s: &str) -> Result<AccountId32, String> {
+    let bytes = hex::decode(s.trim_start_matches("0x"))
+        .map_err(|e| format!("Invalid public key {s}: {e}"))?;
+    let bytes: [u8; 32] = bytes
+        .try_into()
+        .map_err(|_| format!("Public key {s} must be 32 bytes"))?;
+
+    Ok(AccountId32(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "5F71GxBcHF9UfE6uiDcFsgbPNmHJGwPtNfg3F87HEeVczotP";
+    const PUBLIC_KEY: &str = "0x8675d3e27ba8a6b6a3cf23668d346ba398b8fd7b7c90f9d72789b21458cd192e";
+
+    #[test]
+    fn test_parse_address() {
+        let account = parse_public_key(PUBLIC_KEY).unwrap();
+
+        assert_eq!(
+            ADDRESS.parse::<Address>().unwrap(),
+            Address::Account(account.clone())
+        );
+        assert_eq!(
+            PUBLIC_KEY.parse::<Address>().unwrap(),
+            Address::Account(account.clone())
+        );
+        assert_eq!(
+            "miner1".parse::<Address>().unwrap(),
+            Address::Name("miner1".into())
+        );
+
+        // base58 names as long as an address are still names
+        assert_eq!(
+            "validatorHotkeyNumberOneForSubnetEighteenX"
+                .parse::<Address>()
+                .unwrap(),
+            Address::Name("validatorHotkeyNumberOneForSubnetEighteenX".into())
+        );
+        // last character changed, the checksum no longer matches so it can only be a name
+        assert_eq!(
+            "5F71GxBcHF9UfE6uiDcFsgbPNmHJGwPtNfg3F87HEeVczotQ"
+                .parse::<Address>()
+                .unwrap(),
+            Address::Name("5F71GxBcHF9UfE6uiDcFsgbPNmHJGwPtNfg3F87HEeVczotQ".into())
+        );
+        // the same key with the Polkadot prefix
+        assert!(encode_ss58(&account, 0).parse::<Address>().is_err());
+        assert!("0x1234".parse::<Address>().is_err());
+        assert!("../default".parse::<Address>().is_err());
+    }
+
+    #[test]
+    fn test_hotkey_keystore() {
+        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+        let keyfile = Path::new(&manifest_dir).join("resources/testkey/coldkeypub.txt");
+        let key_path = std::env::temp_dir().join(format!("tensors-address-{}", std::process::id()));
+        std::fs::create_dir_all(key_path.join("default/hotkeys")).unwrap();
+        std::fs::copy(&keyfile, key_path.join("default/hotkeys/miner1")).unwrap();
+
+        let by_name = Address::Name("miner1".into()).hotkey_keystore(&key_path, "default");
+        let by_address = ADDRESS
+            .parse::<Address>()
+            .unwrap()
+            .hotkey_keystore(&key_path, "default");
+        let unknown = Address::Account(AccountId32([0; 32])).hotkey_keystore(&key_path, "default");
+        std::fs::remove_dir_all(&key_path).unwrap();
+
+        assert_eq!(by_name.unwrap().ss58_address(), ADDRESS);
+        assert_eq!(by_address.unwrap().ss58_address(), ADDRESS);
+        assert!(matches!(unknown, Err(KeystoreError::UnknownHotkey(_))));
+    }
+
+    #[test]
+    fn test_ss58_roundtrip() {
+        let account = parse_public_key(PUBLIC_KEY).unwrap();
+
+        assert_eq!(encode_ss58(&account, SS58_PREFIX), ADDRESS);
+
+        let polkadot = encode_ss58(&account, 0);
+        assert_eq!(decode_ss58(&polkadot).unwrap(), (account, 0));
+    }
+}
@@ -0,0 +1,56 @@
+use std::str::FromStr;
+
+use subxt::utils::H256;
+
+/// block that storage queries read at
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum At {
+    #[default]
+    Latest,
+    Block(H256),
+}
+
+/// a block given by number or by 0x prefixed hash
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockId {
+    Number(u32),
+    Hash(H256),
+}
+
+impl FromStr for BlockId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if let Some(hash) = s.strip_prefix("0x") {
+            let bytes = hex::decode(hash).map_err(|e| format!("Invalid block hash {s}: {e}"))?;
+            let bytes: [u8; 32] = bytes
+                .try_into()
+                .map_err(|_| format!("Block hash {s} must be 32 bytes"))?;
+            return Ok(BlockId::Hash(H256(bytes)));
+        }
+
+        s.parse()
+            .map(BlockId::Number)
+            .map_err(|_| format!("Invalid block number or hash: {s}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_block_id() {
+        assert_eq!("123".parse(), Ok(BlockId::Number(123)));
+        assert_eq!(
+            format!("0x{}", "ab".repeat(32)).parse(),
+            Ok(BlockId::Hash(H256([0xab; 32])))
+        );
+
+        assert!("-1".parse::<BlockId>().is_err());
+        assert!("0x1234".parse::<BlockId>().is_err());
+        assert!("latest".parse::<BlockId>().is_err());
+    }
+}
@@ -0,0 +1,377 @@
+use std::net::IpAddr;
+
+use serde::{Deserialize, Serialize};
+use subxt::utils::AccountId32;
+
+use crate::subtensor::{
+    interface::api::runtime_types::pallet_subtensor::pallet::AxonInfo,
+    net::int_to_ip,
+    queries::{u16_to_fraction, NeuronVectors},
+    units::Balance,
+};
+
+/// every uid of a subnet as it was at `block`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Metagraph {
+    pub netuid: u16,
+    pub block: u64,
+    pub neurons: Vec<Neuron>,
+}
+
+/// one uid, scores are fractions of one and balances are in rao
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Neuron {
+    pub uid: u16,
+    pub hotkey: AccountId32,
+    pub coldkey: AccountId32,
+    pub stake: Balance,
+    pub rank: f64,
+    pub trust: f64,
+    pub consensus: f64,
+    pub incentive: f64,
+    pub dividends: f64,
+    pub emission: Balance,
+    pub validator_trust: f64,
+    pub validator_permit: bool,
+    pub last_update: u64,
+    pub active: bool,
+    pub axon: Option<Axon>,
+}
+
+/// endpoint a hotkey announced with serve_axon
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Axon {
+    pub ip: IpAddr,
+    pub port: u16,
+    pub protocol: u8,
+    pub version: u32,
+    pub block: u64,
+}
+
+impl Metagraph {
+    pub fn neuron(&self, uid: u16) -> Option<&Neuron> {
+        self.neurons.iter().find(|neuron| neuron.uid == uid)
+    }
+
+    /// stake of every uid added up, `None` on overflow
+    pub fn total_stake(&self) -> Option<Balance> {
+        Balance::checked_sum(self.neurons.iter().map(|neuron| neuron.stake))
+    }
+}
+
+/// smallest changes `diff` reports
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiffThresholds {
+    pub stake: Balance,
+    pub incentive: f64,
+    pub emission: Balance,
+}
+
+/// something that happened to a uid between two metagraphs
+#[derive(Debug, Clone, PartialEq)]
+pub enum NeuronChange {
+    Registered {
+        uid: u16,
+        hotkey: AccountId32,
+    },
+    Deregistered {
+        uid: u16,
+        hotkey: AccountId32,
+    },
+    /// the uid was pruned and given to a new hotkey
+    Replaced {
+        uid: u16,
+        old_hotkey: AccountId32,
+        new_hotkey: AccountId32,
+    },
+    Stake {
+        uid: u16,
+        hotkey: AccountId32,
+        from: Balance,
+        to: Balance,
+    },
+    Incentive {
+        uid: u16,
+        hotkey: AccountId32,
+        from: f64,
+        to: f64,
+    },
+    Emission {
+        uid: u16,
+        hotkey: AccountId32,
+        from: Balance,
+        to: Balance,
+    },
+    ValidatorPermit {
+        uid: u16,
+        hotkey: AccountId32,
+        granted: bool,
+    },
+}
+
+impl NeuronChange {
+    pub fn uid(&self) -> u16 {
+        match self {
+            NeuronChange::Registered { uid, .. }
+            | NeuronChange::Deregistered { uid, .. }
+            | NeuronChange::Replaced { uid, .. }
+            | NeuronChange::Stake { uid, .. }
+            | NeuronChange::Incentive { uid, .. }
+            | NeuronChange::Emission { uid, .. }
+            | NeuronChange::ValidatorPermit { uid, .. } => *uid,
+        }
+    }
+}
+
+/// changes from `from` to `to` in uid order, value changes only for uids that kept their hotkey
+pub fn diff(from: &Metagraph, to: &Metagraph, thresholds: &DiffThresholds) -> Vec<NeuronChange> {
+    let mut changes = Vec::new();
+
+    for old in &from.neurons {
+        let uid = old.uid;
+        let Some(new) = to.neuron(uid) else {
+            changes.push(NeuronChange::Deregistered {
+                uid,
+                hotkey: old.hotkey.clone(),
+            });
+            continue;
+        };
+
+        if new.hotkey != old.hotkey {
+            changes.push(NeuronChange::Replaced {
+                uid,
+                old_hotkey: old.hotkey.clone(),
+                new_hotkey: new.hotkey.clone(),
+            });
+            continue;
+        }
+
+        let hotkey = || new.hotkey.clone();
+        if abs_diff(old.stake, new.stake) > thresholds.stake {
+            changes.push(NeuronChange::Stake {
+                uid,
+                hotkey: hotkey(),
+                from: old.stake,
+                to: new.stake,
+            });
+        }
+        if (new.incentive - old.incentive).abs() > thresholds.incentive {
+            changes.push(NeuronChange::Incentive {
+                uid,
+                hotkey: hotkey(),
+                from: old.incentive,
+                to: new.incentive,
+            });
+        }
+        if abs_diff(old.emission, new.emission) > thresholds.emission {
+            changes.push(NeuronChange::Emission {
+                uid,
+                hotkey: hotkey(),
+                from: old.emission,
+                to: new.emission,
+            });
+        }
+        if new.validator_permit != old.validator_permit {
+            changes.push(NeuronChange::ValidatorPermit {
+                uid,
+                hotkey: hotkey(),
+                granted: new.validator_permit,
+            });
+        }
+    }
+
+    for new in &to.neurons {
+        if from.neuron(new.uid).is_none() {
+            changes.push(NeuronChange::Registered {
+                uid: new.uid,
+                hotkey: new.hotkey.clone(),
+            });
+        }
+    }
+
+    changes.sort_by_key(NeuronChange::uid);
+    changes
+}
+
+fn abs_diff(a: Balance, b: Balance) -> Balance {
+    a.checked_sub(b).unwrap_or_else(|| b.saturating_sub(a))
+}
+
+impl Neuron {
+    /// picks `uid` out of the subnet vectors, missing entries read as zero
+    pub fn from_vectors(
+        uid: u16,
+        hotkey: AccountId32,
+        coldkey: AccountId32,
+        stake: Balance,
+        vectors: &NeuronVectors,
+        axon: Option<&AxonInfo>,
+    ) -> Self {
+        let index = usize::from(uid);
+        let fraction = |values: &[u16]| u16_to_fraction(values.get(index).copied().unwrap_or(0));
+
+        Neuron {
+            uid,
+            hotkey,
+            coldkey,
+            stake,
+            rank: fraction(&vectors.rank),
+            trust: fraction(&vectors.trust),
+            consensus: fraction(&vectors.consensus),
+            incentive: fraction(&vectors.incentive),
+            dividends: fraction(&vectors.dividends),
+            emission: Balance::from_rao(vectors.emission.get(index).copied().unwrap_or(0)),
+            validator_trust: fraction(&vectors.validator_trust),
+            validator_permit: vectors
+                .validator_permit
+                .get(index)
+                .copied()
+                .unwrap_or(false),
+            last_update: vectors.last_update.get(index).copied().unwrap_or(0),
+            active: vectors.active.get(index).copied().unwrap_or(false),
+            axon: axon.and_then(|axon| {
+                Some(Axon {
+                    ip: int_to_ip(axon.ip, axon.ip_type)?,
+                    port: axon.port,
+                    protocol: axon.protocol,
+                    version: axon.version,
+                    block: axon.block,
+                })
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neuron_from_vectors() {
+        let vectors = NeuronVectors {
+            rank: vec![0, u16::MAX],
+            incentive: vec![0, 0],
+            emission: vec![10, 20],
+            validator_permit: vec![false, true],
+            last_update: vec![5, 6],
+            active: vec![true, true],
+            ..Default::default()
+        };
+        let axon = AxonInfo {
+            block: 3,
+            version: 1,
+            ip: 0x7f00_0001,
+            port: 8091,
+            ip_type: 4,
+            protocol: 4,
+            placeholder1: 0,
+            placeholder2: 0,
+        };
+
+        let neuron = Neuron::from_vectors(
+            1,
+            AccountId32([1; 32]),
+            AccountId32([2; 32]),
+            Balance::from_rao(100),
+            &vectors,
+            Some(&axon),
+        );
+        assert_eq!(neuron.rank, 1.0);
+        assert_eq!(neuron.trust, 0.0);
+        assert_eq!(neuron.emission, Balance::from_rao(20));
+        assert!(neuron.validator_permit);
+        assert_eq!(neuron.last_update, 6);
+        assert_eq!(
+            neuron.axon.unwrap().ip,
+            "127.0.0.1".parse::<IpAddr>().unwrap()
+        );
+
+        // a uid past the end of the vectors reads as zero
+        let neuron = Neuron::from_vectors(
+            5,
+            AccountId32([1; 32]),
+            AccountId32([2; 32]),
+            Balance::ZERO,
+            &vectors,
+            None,
+        );
+        assert_eq!(neuron.emission, Balance::ZERO);
+        assert!(!neuron.active);
+        assert!(neuron.axon.is_none());
+    }
+
+    fn neuron(uid: u16, hotkey: u8) -> Neuron {
+        Neuron::from_vectors(
+            uid,
+            AccountId32([hotkey; 32]),
+            AccountId32([0; 32]),
+            Balance::from_tao(10).unwrap(),
+            &NeuronVectors::default(),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_diff() {
+        let thresholds = DiffThresholds {
+            stake: Balance::from_tao(1).unwrap(),
+            incentive: 0.01,
+            emission: Balance::ZERO,
+        };
+        let from = Metagraph {
+            netuid: 1,
+            block: 100,
+            neurons: vec![neuron(0, 1), neuron(1, 2), neuron(2, 3), neuron(3, 4)],
+        };
+
+        let mut to = from.clone();
+        to.block = 200;
+        // uid 0 is pruned and taken by a new hotkey, uid 3 is gone and uid 4 is new
+        to.neurons[0] = neuron(0, 9);
+        to.neurons[1].stake = Balance::from_tao(12).unwrap();
+        to.neurons[1].validator_permit = true;
+        to.neurons[2].stake = Balance::from_tao(9).unwrap();
+        to.neurons[2].incentive = 0.005;
+        to.neurons[2].emission = Balance::from_rao(1);
+        to.neurons[3] = neuron(4, 5);
+
+        let changes = diff(&from, &to, &thresholds);
+        assert_eq!(
+            changes,
+            vec![
+                NeuronChange::Replaced {
+                    uid: 0,
+                    old_hotkey: AccountId32([1; 32]),
+                    new_hotkey: AccountId32([9; 32]),
+                },
+                NeuronChange::Stake {
+                    uid: 1,
+                    hotkey: AccountId32([2; 32]),
+                    from: Balance::from_tao(10).unwrap(),
+                    to: Balance::from_tao(12).unwrap(),
+                },
+                NeuronChange::ValidatorPermit {
+                    uid: 1,
+                    hotkey: AccountId32([2; 32]),
+                    granted: true,
+                },
+                NeuronChange::Emission {
+                    uid: 2,
+                    hotkey: AccountId32([3; 32]),
+                    from: Balance::ZERO,
+                    to: Balance::from_rao(1),
+                },
+                NeuronChange::Deregistered {
+                    uid: 3,
+                    hotkey: AccountId32([4; 32]),
+                },
+                NeuronChange::Registered {
+                    uid: 4,
+                    hotkey: AccountId32([5; 32]),
+                },
+            ]
+        );
+
+        assert!(diff(&from, &from, &thresholds).is_empty());
+    }
+}
@@ -1,2 +1,11 @@
+pub mod address;
+pub mod block;
 pub mod interface;
-
+pub mod metagraph;
+pub mod net;
+pub mod offline;
+pub mod pow;
+pub mod queries;
+pub mod subnet;
+pub mod units;
+pub mod weights;
@@ -0,0 +1,40 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// encodes an address the way `AxonInfo` and `PrometheusInfo` store it, as the ip and its type
+pub fn ip_to_int(ip: IpAddr) -> (u128, u8) {
+    match ip {
+        IpAddr::V4(ip) => (u128::from(u32::from(ip)), 4),
+        IpAddr::V6(ip) => (u128::from(ip), 6),
+    }
+}
+
+/// decodes an ip stored on chain, `None` for an unknown ip type or an out of range IPv4
+pub fn int_to_ip(ip: u128, ip_type: u8) -> Option<IpAddr> {
+    match ip_type {
+        4 => u32::try_from(ip)
+            .ok()
+            .map(|ip| IpAddr::V4(Ipv4Addr::from(ip))),
+        6 => Some(IpAddr::V6(Ipv6Addr::from(ip))),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ip_roundtrip() {
+        let v4: IpAddr = "192.168.1.10".parse().unwrap();
+        assert_eq!(ip_to_int(v4), (3_232_235_786, 4));
+        assert_eq!(int_to_ip(3_232_235_786, 4), Some(v4));
+
+        let v6: IpAddr = "2001:db8::1".parse().unwrap();
+        let (ip, ip_type) = ip_to_int(v6);
+        assert_eq!(ip_type, 6);
+        assert_eq!(int_to_ip(ip, ip_type), Some(v6));
+
+        assert_eq!(int_to_ip(u128::from(u32::MAX) + 1, 4), None);
+        assert_eq!(int_to_ip(1, 5), None);
+    }
+}
@@ -0,0 +1,365 @@
+use serde::{Deserialize, Serialize};
+use sp_core::hashing::blake2_256;
+use subxt::{
+    ext::codec::{Compact, Decode, Encode},
+    utils::{AccountId32, Era as MortalityEra, MultiAddress, MultiSignature},
+};
+use subxt_signer::sr25519::Keypair;
+
+use crate::subtensor::{
+    address::{decode_ss58, encode_ss58, SS58_PREFIX},
+    interface::api,
+};
+
+/// version 4 extrinsic with the signed bit set
+const SIGNED_EXTRINSIC_V4: u8 = 0b1000_0000 + 4;
+
+/// payloads longer than this are signed by their blake2 hash
+const MAX_UNHASHED_PAYLOAD: usize = 256;
+
+/// a call built while online, everything needed to sign it without a connection
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnsignedTx {
+    pub pallet: String,
+    pub call: String,
+    /// call arguments as given to `tx build`, for review before signing
+    pub args: String,
+    /// SS58 address of the account that has to sign
+    pub signer: String,
+    #[serde(with = "hex_bytes")]
+    pub call_data: Vec<u8>,
+    pub nonce: u64,
+    /// `None` for an immortal transaction
+    pub era: Option<Era>,
+    #[serde(with = "hex_bytes")]
+    pub genesis_hash: Vec<u8>,
+    pub spec_version: u32,
+    pub transaction_version: u32,
+    /// signed extension data that goes into the extrinsic
+    #[serde(with = "hex_bytes")]
+    pub extra: Vec<u8>,
+    /// signed extension data that is only signed, like the genesis and spec version
+    #[serde(with = "hex_bytes")]
+    pub additional: Vec<u8>,
+}
+
+/// mortal transactions are valid for `period` blocks from `block`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Era {
+    pub period: u64,
+    pub block: u64,
+    #[serde(with = "hex_bytes")]
+    pub block_hash: Vec<u8>,
+}
+
+/// an extrinsic ready to broadcast, with the details it was built from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedTx {
+    #[serde(flatten)]
+    pub unsigned: UnsignedTx,
+    #[serde(with = "hex_bytes")]
+    pub extrinsic: Vec<u8>,
+}
+
+impl UnsignedTx {
+    pub fn signer_account(&self) -> Result<AccountId32, String> {
+        let (account, prefix) = decode_ss58(&self.signer)?;
+        if prefix != SS58_PREFIX {
+            return Err(format!(
+                "Signer {} uses SS58 prefix {prefix}, expected {SS58_PREFIX}",
+                self.signer
+            ));
+        }
+
+        Ok(account)
+    }
+
+    /// bytes the signature covers, hashed when longer than 256 bytes as the runtime expects
+    pub fn signer_payload(&self) -> Vec<u8> {
+        let mut payload = self.call_data.clone();
+        payload.extend(&self.extra);
+        payload.extend(&self.additional);
+
+        if payload.len() > MAX_UNHASHED_PAYLOAD {
+            blake2_256(&payload).to_vec()
+        } else {
+            payload
+        }
+    }
+
+    /// signed extension data for the nonce, era and runtime in this file, in the order of the
+    /// runtime's signed extensions, `tx build` never adds a tip
+    fn encode_extensions(&self) -> (Vec<u8>, Vec<u8>) {
+        let mut extra = Vec::new();
+        let mut additional = Vec::new();
+
+        // CheckSpecVersion, CheckTxVersion and CheckGenesis only add to the signed payload
+        self.spec_version.encode_to(&mut additional);
+        self.transaction_version.encode_to(&mut additional);
+        additional.extend(&self.genesis_hash);
+
+        // CheckMortality signs the hash of the era's first block, or the genesis when immortal
+        match &self.era {
+            Some(era) => {
+                MortalityEra::mortal(era.period, era.block).encode_to(&mut extra);
+                additional.extend(&era.block_hash);
+            }
+            None => {
+                MortalityEra::Immortal.encode_to(&mut extra);
+                additional.extend(&self.genesis_hash);
+            }
+        }
+
+        // CheckNonce and ChargeTransactionPayment
+        Compact(self.nonce).encode_to(&mut extra);
+        Compact(0u128).encode_to(&mut extra);
+
+        (extra, additional)
+    }
+
+    /// checks the signed extension bytes match the nonce, era and runtime shown for review
+    pub fn check_extensions(&self) -> Result<(), String> {
+        if self.genesis_hash.len() != 32
+            || self
+                .era
+                .as_ref()
+                .is_some_and(|era| era.block_hash.len() != 32)
+        {
+            return Err("Genesis and era block hashes must be 32 bytes".into());
+        }
+
+        let (extra, additional) = self.encode_extensions();
+        if self.extra != extra || self.additional != additional {
+            return Err(
+                "Signed extension data does not match the nonce, era and runtime of the transaction"
+                    .into(),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// `call_data` decoded against the runtime compiled in, so the call can be reviewed without
+    /// trusting the machine that built it, it has to be the pallet and call the file names
+    pub fn decode_call(&self) -> Result<String, String> {
+        let input = &mut &self.call_data[..];
+        let call = <api::Call as Decode>::decode(input)
+            .map_err(|e| format!("Call data does not decode as a call of this runtime: {e}"))?;
+        if !input.is_empty() {
+            return Err("Call data has bytes left over after the call".into());
+        }
+
+        let decoded = format!("{call:?}");
+        let (pallet, name) =
+            call_names(&decoded).ok_or_else(|| format!("Could not name the call {decoded}"))?;
+        if pallet != self.pallet || name != self.call {
+            return Err(format!(
+                "Call data is {pallet}.{name}, the file says {}.{}",
+                self.pallet, self.call
+            ));
+        }
+
+        Ok(decoded)
+    }
+
+    /// signs with `keypair`, which has to be the account the transaction was built for
+    pub fn sign(self, keypair: &Keypair) -> Result<SignedTx, String> {
+        self.check_extensions()?;
+        self.decode_call()?;
+
+        let account = keypair.public_key().to_account_id();
+        if self.signer_account()? != account {
+            return Err(format!(
+                "Transaction has to be signed by {}, not {}",
+                self.signer,
+                encode_ss58(&account, SS58_PREFIX)
+            ));
+        }
+
+        let signature = keypair.sign(&self.signer_payload());
+
+        let mut inner = vec![SIGNED_EXTRINSIC_V4];
+        MultiAddress::<AccountId32, ()>::Id(account).encode_to(&mut inner);
+        MultiSignature::Sr25519(signature.0).encode_to(&mut inner);
+        inner.extend(&self.extra);
+        inner.extend(&self.call_data);
+
+        let mut extrinsic = Compact(inner.len() as u32).encode();
+        extrinsic.extend(inner);
+
+        Ok(SignedTx {
+            unsigned: self,
+            extrinsic,
+        })
+    }
+}
+
+/// pallet and call name of a call printed with `Debug`, like `Pallet(call { .. })`
+fn call_names(decoded: &str) -> Option<(&str, &str)> {
+    let (pallet, rest) = decoded.split_once('(')?;
+    let end = rest.find([' ', '(', ')', '{']).unwrap_or(rest.len());
+
+    Some((pallet, &rest[..end]))
+}
+
+/// byte fields are written as 0x prefixed hex
+mod hex_bytes {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("0x{}", hex::encode(bytes)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        hex::decode(s.trim_start_matches("0x")).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use subxt_signer::sr25519::{dev, verify};
+
+    use super::*;
+
+    /// System.remark of the bytes 1, 2 and 3
+    const REMARK: [u8; 6] = [0, 0, 0x0c, 1, 2, 3];
+
+    fn unsigned_tx(signer: &Keypair, call_data: Vec<u8>) -> UnsignedTx {
+        let mut tx = UnsignedTx {
+            pallet: "System".into(),
+            call: "remark".into(),
+            args: "(0x010203)".into(),
+            signer: encode_ss58(&signer.public_key().to_account_id(), SS58_PREFIX),
+            call_data,
+            nonce: 7,
+            era: Some(Era {
+                period: 64,
+                block: 100,
+                block_hash: vec![2; 32],
+            }),
+            genesis_hash: vec![1; 32],
+            spec_version: 150,
+            transaction_version: 1,
+            extra: Vec::new(),
+            additional: Vec::new(),
+        };
+        (tx.extra, tx.additional) = tx.encode_extensions();
+        tx
+    }
+
+    #[test]
+    fn test_sign_offline() {
+        let alice = dev::alice();
+        let tx = unsigned_tx(&alice, REMARK.to_vec());
+        let payload = tx.signer_payload();
+        assert_eq!(payload.len(), 6 + 4 + 72);
+
+        let signed = tx.clone().sign(&alice).unwrap();
+        let extrinsic = &signed.extrinsic;
+
+        // compact length, version byte, address, signature, extra and call data
+        let body_len = 1 + 33 + 65 + 4 + 6;
+        assert_eq!(extrinsic.len(), 2 + body_len);
+        assert_eq!(&extrinsic[..2], &Compact(body_len as u32).encode()[..]);
+        assert_eq!(extrinsic[2], SIGNED_EXTRINSIC_V4);
+        assert_eq!(&extrinsic[4..36], alice.public_key().0.as_slice());
+        assert_eq!(extrinsic[36], 1);
+        assert!(extrinsic.ends_with(&[0x45, 0x02, 0x1c, 0x00, 0, 0, 0x0c, 1, 2, 3]));
+
+        let signature = subxt_signer::sr25519::Signature(extrinsic[37..101].try_into().unwrap());
+        assert!(verify(&signature, &payload, &alice.public_key()));
+
+        assert!(tx.sign(&dev::bob()).is_err());
+    }
+
+    #[test]
+    fn test_encode_extensions() {
+        let mut tx = unsigned_tx(&dev::alice(), REMARK.to_vec());
+        // era of 64 blocks from block 100 is phase 36, then nonce 7 and no tip
+        assert_eq!(tx.extra, [0x45, 0x02, 0x1c, 0x00]);
+        assert_eq!(tx.additional[..8], [150, 0, 0, 0, 1, 0, 0, 0]);
+        assert_eq!(tx.additional[8..40], [1; 32]);
+        assert_eq!(tx.additional[40..], [2; 32]);
+
+        tx.era = None;
+        let (extra, additional) = tx.encode_extensions();
+        assert_eq!(extra, [0x00, 0x1c, 0x00]);
+        assert_eq!(additional[40..], [1; 32]);
+    }
+
+    #[test]
+    fn test_sign_rejects_mismatched_extensions() {
+        let alice = dev::alice();
+        let tx = unsigned_tx(&alice, REMARK.to_vec());
+        assert!(tx.check_extensions().is_ok());
+
+        // the shown fields changed but the signed bytes did not
+        let mut nonce = tx.clone();
+        nonce.nonce = 8;
+        assert!(nonce.sign(&alice).is_err());
+
+        let mut immortal = tx.clone();
+        immortal.era = None;
+        assert!(immortal.sign(&alice).is_err());
+
+        let mut spec_version = tx.clone();
+        spec_version.spec_version = 151;
+        assert!(spec_version.sign(&alice).is_err());
+
+        // the signed bytes changed but the shown fields did not
+        let mut extra = tx.clone();
+        extra.extra[2] = 0x20;
+        assert!(extra.sign(&alice).is_err());
+
+        let mut genesis = tx.clone();
+        genesis.genesis_hash = vec![1; 31];
+        (genesis.extra, genesis.additional) = genesis.encode_extensions();
+        assert!(genesis.sign(&alice).is_err());
+    }
+
+    #[test]
+    fn test_decode_call() {
+        let alice = dev::alice();
+        let tx = unsigned_tx(&alice, REMARK.to_vec());
+        assert_eq!(
+            tx.decode_call().unwrap(),
+            "System(remark { remark: [1, 2, 3] })"
+        );
+
+        // the file describes another call than the one that would be signed
+        let mut renamed = tx.clone();
+        renamed.call = "remark_with_event".into();
+        assert!(renamed.sign(&alice).is_err());
+
+        let mut pallet = tx.clone();
+        pallet.pallet = "Balances".into();
+        assert!(pallet.sign(&alice).is_err());
+
+        // no such pallet, and a remark with a byte too many
+        assert!(unsigned_tx(&alice, vec![0xff, 0]).sign(&alice).is_err());
+        let mut trailing = REMARK.to_vec();
+        trailing.push(4);
+        assert!(unsigned_tx(&alice, trailing).sign(&alice).is_err());
+    }
+
+    #[test]
+    fn test_long_payload_is_hashed() {
+        let tx = unsigned_tx(&dev::alice(), vec![0; 300]);
+        assert_eq!(tx.signer_payload().len(), 32);
+    }
+
+    #[test]
+    fn test_tx_file_roundtrip() {
+        let signed = unsigned_tx(&dev::alice(), REMARK.to_vec())
+            .sign(&dev::alice())
+            .unwrap();
+
+        let json = serde_json::to_string(&signed).unwrap();
+        assert!(json.contains("\"call_data\":\"0x00000c010203\""));
+        assert_eq!(serde_json::from_str::<SignedTx>(&json).unwrap(), signed);
+
+        let unsigned: UnsignedTx = serde_json::from_str(&json).unwrap();
+        assert_eq!(unsigned, signed.unsigned);
+    }
+}
@@ -0,0 +1,154 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use sp_core::{
+    hashing::{keccak_256, sha2_256},
+    U256,
+};
+use subxt::utils::AccountId32;
+
+/// nonces each thread tries between checks of the stop flag
+const BATCH_SIZE: u64 = 10_000;
+
+/// keccak of the block hash followed by the hotkey, the part of the seal that is fixed per block
+pub fn block_and_hotkey_hash(block_hash: &[u8; 32], hotkey: &AccountId32) -> [u8; 32] {
+    let mut bytes = [0u8; 64];
+    bytes[..32].copy_from_slice(block_hash);
+    bytes[32..].copy_from_slice(hotkey.as_ref());
+    keccak_256(&bytes)
+}
+
+/// seal for `nonce` the way subtensor computes it in `create_seal_hash`
+pub fn seal_hash(block_and_hotkey: &[u8; 32], nonce: u64) -> [u8; 32] {
+    let mut bytes = [0u8; 40];
+    bytes[..8].copy_from_slice(&nonce.to_le_bytes());
+    bytes[8..].copy_from_slice(block_and_hotkey);
+    keccak_256(&sha2_256(&bytes))
+}
+
+/// a seal is valid when its big endian value times `difficulty` does not overflow 256 bits
+pub fn meets_difficulty(seal: &[u8; 32], difficulty: u64) -> bool {
+    let (_, overflowed) = U256::from_big_endian(seal).overflowing_mul(U256::from(difficulty));
+    !overflowed
+}
+
+/// nonce and seal that satisfy the difficulty for one block
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Solution {
+    pub nonce: u64,
+    pub seal: [u8; 32],
+}
+
+/// PoW search running on background threads, stopped when dropped
+pub struct Solver {
+    stop: Arc<AtomicBool>,
+    hashes: Arc<AtomicU64>,
+    solutions: mpsc::Receiver<Solution>,
+    handles: Vec<thread::JoinHandle<()>>,
+}
+
+impl Solver {
+    /// starts `threads` workers searching disjoint nonce sequences from a time based offset
+    pub fn spawn(block_and_hotkey: [u8; 32], difficulty: u64, threads: usize) -> Self {
+        let threads = threads.max(1) as u64;
+        let stop = Arc::new(AtomicBool::new(false));
+        let hashes = Arc::new(AtomicU64::new(0));
+        let (sender, solutions) = mpsc::channel();
+
+        let start = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or_default();
+
+        let handles = (0..threads)
+            .map(|index| {
+                let stop = stop.clone();
+                let hashes = hashes.clone();
+                let sender = sender.clone();
+
+                thread::spawn(move || {
+                    let mut nonce = start.wrapping_add(index);
+                    while !stop.load(Ordering::Relaxed) {
+                        for _ in 0..BATCH_SIZE {
+                            let seal = seal_hash(&block_and_hotkey, nonce);
+                            if meets_difficulty(&seal, difficulty) {
+                                stop.store(true, Ordering::Relaxed);
+                                let _ = sender.send(Solution { nonce, seal });
+                                return;
+                            }
+                            nonce = nonce.wrapping_add(threads);
+                        }
+                        hashes.fetch_add(BATCH_SIZE, Ordering::Relaxed);
+                    }
+                })
+            })
+            .collect();
+
+        Solver {
+            stop,
+            hashes,
+            solutions,
+            handles,
+        }
+    }
+
+    /// number of seals tried so far
+    pub fn hashes(&self) -> u64 {
+        self.hashes.load(Ordering::Relaxed)
+    }
+
+    /// solution found by any worker, without waiting
+    pub fn try_solution(&self) -> Option<Solution> {
+        self.solutions.try_recv().ok()
+    }
+}
+
+impl Drop for Solver {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        for handle in self.handles.drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_meets_difficulty() {
+        let mut seal = [0u8; 32];
+        assert!(meets_difficulty(&seal, u64::MAX));
+
+        seal[0] = 0x80;
+        assert!(meets_difficulty(&seal, 1));
+        assert!(!meets_difficulty(&seal, 2));
+
+        seal = [0xff; 32];
+        assert!(meets_difficulty(&seal, 1));
+        assert!(!meets_difficulty(&seal, 2));
+    }
+
+    #[test]
+    fn test_solver_finds_valid_seal() {
+        let hotkey = AccountId32([7u8; 32]);
+        let block_and_hotkey = block_and_hotkey_hash(&[1u8; 32], &hotkey);
+        let difficulty = 1_000;
+
+        let solver = Solver::spawn(block_and_hotkey, difficulty, 2);
+        let solution = solver
+            .solutions
+            .recv_timeout(std::time::Duration::from_secs(30))
+            .unwrap();
+
+        assert_eq!(seal_hash(&block_and_hotkey, solution.nonce), solution.seal);
+        assert!(meets_difficulty(&solution.seal, difficulty));
+    }
+}
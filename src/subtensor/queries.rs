@@ -0,0 +1,720 @@
+use std::collections::BTreeMap;
+
+use futures::{stream, StreamExt, TryStreamExt};
+use subxt::{
+    backend::{legacy::LegacyRpcMethods, rpc::RpcClient},
+    storage::Storage,
+    utils::{AccountId32, H256},
+    OnlineClient, SubstrateConfig,
+};
+
+use crate::subtensor::{
+    block::At,
+    interface::api::{
+        self,
+        runtime_types::{
+            pallet_balances::AccountData,
+            pallet_subtensor::pallet::{AxonInfo, PrometheusInfo},
+        },
+    },
+    metagraph::{Metagraph, Neuron},
+    subnet::{Hyperparameters, SubnetInfo},
+    units::Balance,
+};
+
+/// neurons read at once, each takes three storage reads and the RPC client refuses more than
+/// 256 requests in flight
+const CONCURRENT_NEURONS: usize = 64;
+
+/// storage as of the block `at` refers to
+async fn storage_at(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+) -> Result<Storage<SubstrateConfig, OnlineClient<SubstrateConfig>>, subxt::Error> {
+    match at {
+        At::Latest => client.storage().at_latest().await,
+        At::Block(hash) => Ok(client.storage().at(hash)),
+    }
+}
+
+/// hash of block `number` from the node's `chain_getBlockHash`, which, unlike `block_hash`,
+/// reaches past the last 4096 blocks on an archive node
+pub async fn block_hash_from_rpc(
+    endpoint: &str,
+    number: u32,
+) -> Result<Option<H256>, subxt::Error> {
+    let rpc = LegacyRpcMethods::<SubstrateConfig>::new(RpcClient::from_url(endpoint).await?);
+
+    rpc.chain_get_block_hash(Some(number.into())).await
+}
+
+/// number of the block `at` refers to
+pub async fn block_number(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+) -> Result<u64, subxt::Error> {
+    let block = match at {
+        At::Latest => client.blocks().at_latest().await?,
+        At::Block(hash) => client.blocks().at(hash).await?,
+    };
+
+    Ok(block.number().into())
+}
+
+/// hash of block `number`, `None` if the chain no longer keeps it or has not produced it yet
+pub async fn block_hash(
+    client: &OnlineClient<SubstrateConfig>,
+    number: u32,
+) -> Result<Option<H256>, subxt::Error> {
+    let storage_query = api::storage().system().block_hash(number);
+
+    let hash = storage_at(client, At::Latest)
+        .await?
+        .fetch(&storage_query)
+        .await?;
+
+    // BlockHash is a value query, blocks it does not hold read as the zero hash
+    Ok(hash.filter(|hash| !hash.is_zero()))
+}
+
+/// balances of `account` in rao, zero for accounts that do not exist yet
+pub async fn account_data(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+    account: &AccountId32,
+) -> Result<AccountData<u64>, subxt::Error> {
+    let storage_query = api::storage().system().account(account);
+
+    let account_info = storage_at(client, at)
+        .await?
+        .fetch_or_default(&storage_query)
+        .await?;
+
+    Ok(account_info.data)
+}
+
+/// free balance of `account`, zero for accounts that do not exist yet
+pub async fn free_balance(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+    account: &AccountId32,
+) -> Result<Balance, subxt::Error> {
+    Ok(Balance::from_rao(
+        account_data(client, at, account).await?.free,
+    ))
+}
+
+/// stake that `coldkey` holds across all hotkeys
+pub async fn total_coldkey_stake(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+    coldkey: &AccountId32,
+) -> Result<Balance, subxt::Error> {
+    let storage_query = api::storage()
+        .subtensor_module()
+        .total_coldkey_stake(coldkey);
+
+    storage_at(client, at)
+        .await?
+        .fetch_or_default(&storage_query)
+        .await
+        .map(Balance::from_rao)
+}
+
+/// minimum balance an account needs to stay alive
+pub async fn existential_deposit(
+    client: &OnlineClient<SubstrateConfig>,
+) -> Result<Balance, subxt::Error> {
+    client
+        .constants()
+        .at(&api::constants().balances().existential_deposit())
+        .map(Balance::from_rao)
+}
+
+/// netuids of every subnet that currently exists, in ascending order
+pub async fn subnets(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+) -> Result<Vec<u16>, subxt::Error> {
+    networks_added(&storage_at(client, at).await?).await
+}
+
+async fn networks_added(
+    storage: &Storage<SubstrateConfig, OnlineClient<SubstrateConfig>>,
+) -> Result<Vec<u16>, subxt::Error> {
+    let storage_query = api::storage().subtensor_module().networks_added_iter();
+
+    let mut iter = storage.iter(storage_query).await?;
+
+    let mut netuids = Vec::new();
+    while let Some(entry) = iter.next().await {
+        let (key, added) = entry?;
+        // NetworksAdded uses the identity hasher, so the netuid ends the key
+        if let (true, Some(netuid)) = (added, u16_from_key(&key)) {
+            netuids.push(netuid);
+        }
+    }
+
+    netuids.sort_unstable();
+    Ok(netuids)
+}
+
+/// uid of `hotkey` on subnet `netuid`, `None` if it is not registered there
+pub async fn uid(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+    netuid: u16,
+    hotkey: &AccountId32,
+) -> Result<Option<u16>, subxt::Error> {
+    let storage_query = api::storage().subtensor_module().uids(netuid, hotkey);
+
+    storage_at(client, at).await?.fetch(&storage_query).await
+}
+
+/// coldkey that owns `hotkey`, `None` if the hotkey was never registered
+pub async fn owner(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+    hotkey: &AccountId32,
+) -> Result<Option<AccountId32>, subxt::Error> {
+    let storage_query = api::storage().subtensor_module().owner(hotkey);
+
+    storage_at(client, at).await?.fetch(&storage_query).await
+}
+
+/// stake that `coldkey` holds on `hotkey`
+pub async fn stake(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+    hotkey: &AccountId32,
+    coldkey: &AccountId32,
+) -> Result<Balance, subxt::Error> {
+    let storage_query = api::storage().subtensor_module().stake(hotkey, coldkey);
+
+    storage_at(client, at)
+        .await?
+        .fetch_or_default(&storage_query)
+        .await
+        .map(Balance::from_rao)
+}
+
+/// current PoW registration difficulty of subnet `netuid`
+pub async fn difficulty(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+    netuid: u16,
+) -> Result<u64, subxt::Error> {
+    let storage_query = api::storage().subtensor_module().difficulty(netuid);
+
+    storage_at(client, at)
+        .await?
+        .fetch_or_default(&storage_query)
+        .await
+}
+
+/// current burned registration cost of subnet `netuid`
+pub async fn burn(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+    netuid: u16,
+) -> Result<Balance, subxt::Error> {
+    let storage_query = api::storage().subtensor_module().burn(netuid);
+
+    storage_at(client, at)
+        .await?
+        .fetch_or_default(&storage_query)
+        .await
+        .map(Balance::from_rao)
+}
+
+/// total stake held on `hotkey` by all coldkeys
+pub async fn total_hotkey_stake(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+    hotkey: &AccountId32,
+) -> Result<Balance, subxt::Error> {
+    let storage_query = api::storage().subtensor_module().total_hotkey_stake(hotkey);
+
+    storage_at(client, at)
+        .await?
+        .fetch_or_default(&storage_query)
+        .await
+        .map(Balance::from_rao)
+}
+
+/// number of neurons registered on subnet `netuid`
+pub async fn subnetwork_n(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+    netuid: u16,
+) -> Result<u16, subxt::Error> {
+    let storage_query = api::storage().subtensor_module().subnetwork_n(netuid);
+
+    storage_at(client, at)
+        .await?
+        .fetch_or_default(&storage_query)
+        .await
+}
+
+/// hotkey registered under `uid` on subnet `netuid`
+pub async fn hotkey_of_uid(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+    netuid: u16,
+    uid: u16,
+) -> Result<Option<AccountId32>, subxt::Error> {
+    let storage_query = api::storage().subtensor_module().keys(netuid, uid);
+
+    storage_at(client, at).await?.fetch(&storage_query).await
+}
+
+/// version key that `set_weights` must carry on subnet `netuid`
+pub async fn weights_version_key(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+    netuid: u16,
+) -> Result<u64, subxt::Error> {
+    let storage_query = api::storage()
+        .subtensor_module()
+        .weights_version_key(netuid);
+
+    storage_at(client, at)
+        .await?
+        .fetch_or_default(&storage_query)
+        .await
+}
+
+/// minimum number of non-zero weights a neuron must set on subnet `netuid`
+pub async fn min_allowed_weights(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+    netuid: u16,
+) -> Result<u16, subxt::Error> {
+    let storage_query = api::storage()
+        .subtensor_module()
+        .min_allowed_weights(netuid);
+
+    storage_at(client, at)
+        .await?
+        .fetch_or_default(&storage_query)
+        .await
+}
+
+/// largest share of the total a single weight may have on subnet `netuid`, out of `u16::MAX`
+pub async fn max_weights_limit(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+    netuid: u16,
+) -> Result<u16, subxt::Error> {
+    let storage_query = api::storage().subtensor_module().max_weights_limit(netuid);
+
+    storage_at(client, at)
+        .await?
+        .fetch_or_default(&storage_query)
+        .await
+}
+
+/// blocks a neuron must wait between two weight updates on subnet `netuid`
+pub async fn weights_set_rate_limit(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+    netuid: u16,
+) -> Result<u64, subxt::Error> {
+    let storage_query = api::storage()
+        .subtensor_module()
+        .weights_set_rate_limit(netuid);
+
+    storage_at(client, at)
+        .await?
+        .fetch_or_default(&storage_query)
+        .await
+}
+
+/// block of the last weight update of every uid on subnet `netuid`
+pub async fn last_update(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+    netuid: u16,
+) -> Result<Vec<u64>, subxt::Error> {
+    let storage_query = api::storage().subtensor_module().last_update(netuid);
+
+    storage_at(client, at)
+        .await?
+        .fetch_or_default(&storage_query)
+        .await
+}
+
+/// blocks a hotkey must wait between two serve calls on subnet `netuid`
+pub async fn serving_rate_limit(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+    netuid: u16,
+) -> Result<u64, subxt::Error> {
+    let storage_query = api::storage().subtensor_module().serving_rate_limit(netuid);
+
+    storage_at(client, at)
+        .await?
+        .fetch_or_default(&storage_query)
+        .await
+}
+
+/// axon announced by `hotkey` on subnet `netuid`
+pub async fn axon(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+    netuid: u16,
+    hotkey: &AccountId32,
+) -> Result<Option<AxonInfo>, subxt::Error> {
+    let storage_query = api::storage().subtensor_module().axons(netuid, hotkey);
+
+    storage_at(client, at).await?.fetch(&storage_query).await
+}
+
+/// prometheus endpoint announced by `hotkey` on subnet `netuid`
+pub async fn prometheus(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+    netuid: u16,
+    hotkey: &AccountId32,
+) -> Result<Option<PrometheusInfo>, subxt::Error> {
+    let storage_query = api::storage().subtensor_module().prometheus(netuid, hotkey);
+
+    storage_at(client, at).await?.fetch(&storage_query).await
+}
+
+/// every delegate hotkey with its take out of `u16::MAX`
+pub async fn delegates(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+) -> Result<Vec<(AccountId32, u16)>, subxt::Error> {
+    let storage_query = api::storage().subtensor_module().delegates_iter();
+
+    let mut iter = storage_at(client, at).await?.iter(storage_query).await?;
+
+    let mut delegates = Vec::new();
+    while let Some(entry) = iter.next().await {
+        let (key, take) = entry?;
+        // Delegates uses blake2_128_concat, so the hotkey ends the key
+        if let Some(hotkey) = account_from_key(&key) {
+            delegates.push((hotkey, take));
+        }
+    }
+
+    Ok(delegates)
+}
+
+/// take of the delegate `hotkey`, `None` if it is not a delegate
+pub async fn delegate_take(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+    hotkey: &AccountId32,
+) -> Result<Option<u16>, subxt::Error> {
+    let storage_query = api::storage().subtensor_module().delegates(hotkey);
+
+    storage_at(client, at).await?.fetch(&storage_query).await
+}
+
+/// take new delegates start with, out of `u16::MAX`
+pub async fn default_take(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+) -> Result<u16, subxt::Error> {
+    let storage_query = api::storage().subtensor_module().default_take();
+
+    storage_at(client, at)
+        .await?
+        .fetch_or_default(&storage_query)
+        .await
+}
+
+/// per-uid vectors of a subnet, each indexed by uid
+#[derive(Debug, Clone, Default)]
+pub struct NeuronVectors {
+    pub rank: Vec<u16>,
+    pub trust: Vec<u16>,
+    pub consensus: Vec<u16>,
+    pub incentive: Vec<u16>,
+    pub dividends: Vec<u16>,
+    pub emission: Vec<u64>,
+    pub validator_trust: Vec<u16>,
+    pub validator_permit: Vec<bool>,
+    pub last_update: Vec<u64>,
+    pub active: Vec<bool>,
+}
+
+pub async fn neuron_vectors(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+    netuid: u16,
+) -> Result<NeuronVectors, subxt::Error> {
+    read_neuron_vectors(&storage_at(client, at).await?, netuid).await
+}
+
+/// every uid of subnet `netuid` with its keys, stake, scores and axon, all read at one block
+pub async fn metagraph(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+    netuid: u16,
+) -> Result<Metagraph, subxt::Error> {
+    let block = match at {
+        At::Latest => client.blocks().at_latest().await?,
+        At::Block(hash) => client.blocks().at(hash).await?,
+    };
+    let storage = block.storage();
+    let subtensor = api::storage().subtensor_module();
+
+    let n = storage
+        .fetch_or_default(&subtensor.subnetwork_n(netuid))
+        .await?;
+
+    // Keys uses the identity hasher, so the uid ends the key
+    let mut hotkeys = BTreeMap::new();
+    let mut iter = storage.iter(subtensor.keys_iter1(netuid)).await?;
+    while let Some(entry) = iter.next().await {
+        let (key, hotkey) = entry?;
+        if let Some(uid) = u16_from_key(&key).filter(|&uid| uid < n) {
+            hotkeys.insert(uid, hotkey);
+        }
+    }
+
+    let vectors = read_neuron_vectors(&storage, netuid).await?;
+
+    let (storage, subtensor, vectors) = (&storage, &subtensor, &vectors);
+    let neurons = stream::iter(hotkeys)
+        .map(|(uid, hotkey)| async move {
+            let owner = subtensor.owner(&hotkey);
+            let total_hotkey_stake = subtensor.total_hotkey_stake(&hotkey);
+            let axons = subtensor.axons(netuid, &hotkey);
+            let (coldkey, stake, axon) = futures::try_join!(
+                storage.fetch_or_default(&owner),
+                storage.fetch_or_default(&total_hotkey_stake),
+                storage.fetch(&axons),
+            )?;
+
+            Ok::<_, subxt::Error>(Neuron::from_vectors(
+                uid,
+                hotkey,
+                coldkey,
+                Balance::from_rao(stake),
+                vectors,
+                axon.as_ref(),
+            ))
+        })
+        .buffered(CONCURRENT_NEURONS)
+        .try_collect()
+        .await?;
+
+    Ok(Metagraph {
+        netuid,
+        block: block.number().into(),
+        neurons,
+    })
+}
+
+/// every subnet that exists at `at`, in ascending netuid order
+pub async fn subnet_infos(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+) -> Result<Vec<SubnetInfo>, subxt::Error> {
+    let storage = storage_at(client, at).await?;
+    let subtensor = api::storage().subtensor_module();
+
+    let netuids = networks_added(&storage).await?;
+
+    let mut subnets = Vec::with_capacity(netuids.len());
+    for netuid in netuids {
+        subnets.push(SubnetInfo {
+            netuid,
+            n: storage
+                .fetch_or_default(&subtensor.subnetwork_n(netuid))
+                .await?,
+            max_allowed_uids: storage
+                .fetch_or_default(&subtensor.max_allowed_uids(netuid))
+                .await?,
+            tempo: storage.fetch_or_default(&subtensor.tempo(netuid)).await?,
+            emission: Balance::from_rao(
+                storage
+                    .fetch_or_default(&subtensor.emission_values(netuid))
+                    .await?,
+            ),
+            burn: Balance::from_rao(storage.fetch_or_default(&subtensor.burn(netuid)).await?),
+            difficulty: storage
+                .fetch_or_default(&subtensor.difficulty(netuid))
+                .await?,
+            owner: storage.fetch(&subtensor.subnet_owner(netuid)).await?,
+            registered_at: storage
+                .fetch_or_default(&subtensor.network_registered_at(netuid))
+                .await?,
+        });
+    }
+
+    Ok(subnets)
+}
+
+/// every per-subnet setting of `netuid`, read from the same block
+pub async fn hyperparameters(
+    client: &OnlineClient<SubstrateConfig>,
+    at: At,
+    netuid: u16,
+) -> Result<Hyperparameters, subxt::Error> {
+    let storage = storage_at(client, at).await?;
+    let subtensor = api::storage().subtensor_module();
+
+    Ok(Hyperparameters {
+        rho: storage.fetch_or_default(&subtensor.rho(netuid)).await?,
+        kappa: storage.fetch_or_default(&subtensor.kappa(netuid)).await?,
+        immunity_period: storage
+            .fetch_or_default(&subtensor.immunity_period(netuid))
+            .await?,
+        min_allowed_weights: storage
+            .fetch_or_default(&subtensor.min_allowed_weights(netuid))
+            .await?,
+        max_weights_limit: storage
+            .fetch_or_default(&subtensor.max_weights_limit(netuid))
+            .await?,
+        tempo: storage.fetch_or_default(&subtensor.tempo(netuid)).await?,
+        min_difficulty: storage
+            .fetch_or_default(&subtensor.min_difficulty(netuid))
+            .await?,
+        max_difficulty: storage
+            .fetch_or_default(&subtensor.max_difficulty(netuid))
+            .await?,
+        difficulty: storage
+            .fetch_or_default(&subtensor.difficulty(netuid))
+            .await?,
+        weights_version_key: storage
+            .fetch_or_default(&subtensor.weights_version_key(netuid))
+            .await?,
+        weights_set_rate_limit: storage
+            .fetch_or_default(&subtensor.weights_set_rate_limit(netuid))
+            .await?,
+        adjustment_interval: storage
+            .fetch_or_default(&subtensor.adjustment_interval(netuid))
+            .await?,
+        activity_cutoff: storage
+            .fetch_or_default(&subtensor.activity_cutoff(netuid))
+            .await?,
+        registration_allowed: storage
+            .fetch_or_default(&subtensor.network_registration_allowed(netuid))
+            .await?,
+        pow_registration_allowed: storage
+            .fetch_or_default(&subtensor.network_pow_registration_allowed(netuid))
+            .await?,
+        target_registrations_per_interval: storage
+            .fetch_or_default(&subtensor.target_registrations_per_interval(netuid))
+            .await?,
+        min_burn: Balance::from_rao(
+            storage
+                .fetch_or_default(&subtensor.min_burn(netuid))
+                .await?,
+        ),
+        max_burn: Balance::from_rao(
+            storage
+                .fetch_or_default(&subtensor.max_burn(netuid))
+                .await?,
+        ),
+        bonds_moving_average: storage
+            .fetch_or_default(&subtensor.bonds_moving_average(netuid))
+            .await?,
+        max_registrations_per_block: storage
+            .fetch_or_default(&subtensor.max_registrations_per_block(netuid))
+            .await?,
+        serving_rate_limit: storage
+            .fetch_or_default(&subtensor.serving_rate_limit(netuid))
+            .await?,
+        max_validators: storage
+            .fetch_or_default(&subtensor.max_allowed_validators(netuid))
+            .await?,
+        adjustment_alpha: storage
+            .fetch_or_default(&subtensor.adjustment_alpha(netuid))
+            .await?,
+        max_allowed_uids: storage
+            .fetch_or_default(&subtensor.max_allowed_uids(netuid))
+            .await?,
+        scaling_law_power: storage
+            .fetch_or_default(&subtensor.scaling_law_power(netuid))
+            .await?,
+        validator_prune_len: storage
+            .fetch_or_default(&subtensor.validator_prune_len(netuid))
+            .await?,
+        modality: storage
+            .fetch_or_default(&subtensor.network_modality(netuid))
+            .await?,
+    })
+}
+
+async fn read_neuron_vectors(
+    storage: &Storage<SubstrateConfig, OnlineClient<SubstrateConfig>>,
+    netuid: u16,
+) -> Result<NeuronVectors, subxt::Error> {
+    let subtensor = api::storage().subtensor_module();
+
+    Ok(NeuronVectors {
+        rank: storage.fetch_or_default(&subtensor.rank(netuid)).await?,
+        trust: storage.fetch_or_default(&subtensor.trust(netuid)).await?,
+        consensus: storage
+            .fetch_or_default(&subtensor.consensus(netuid))
+            .await?,
+        incentive: storage
+            .fetch_or_default(&subtensor.incentive(netuid))
+            .await?,
+        dividends: storage
+            .fetch_or_default(&subtensor.dividends(netuid))
+            .await?,
+        emission: storage
+            .fetch_or_default(&subtensor.emission(netuid))
+            .await?,
+        validator_trust: storage
+            .fetch_or_default(&subtensor.validator_trust(netuid))
+            .await?,
+        validator_permit: storage
+            .fetch_or_default(&subtensor.validator_permit(netuid))
+            .await?,
+        last_update: storage
+            .fetch_or_default(&subtensor.last_update(netuid))
+            .await?,
+        active: storage.fetch_or_default(&subtensor.active(netuid)).await?,
+    })
+}
+
+/// converts a u16 fixed point value as stored on chain into a fraction of one
+pub fn u16_to_fraction(value: u16) -> f64 {
+    f64::from(value) / f64::from(u16::MAX)
+}
+
+fn u16_from_key(key: &[u8]) -> Option<u16> {
+    let bytes = key.get(key.len().checked_sub(2)?..)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+fn account_from_key(key: &[u8]) -> Option<AccountId32> {
+    let bytes: [u8; 32] = key.get(key.len().checked_sub(32)?..)?.try_into().ok()?;
+    Some(AccountId32(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_u16_from_key() {
+        let mut key = vec![0xaa; 32];
+        key.extend(3u16.to_le_bytes());
+        assert_eq!(u16_from_key(&key), Some(3));
+        assert_eq!(u16_from_key(&[1]), None);
+    }
+
+    #[test]
+    fn test_account_from_key() {
+        let mut key = vec![0xaa; 48];
+        key.extend([7u8; 32]);
+        assert_eq!(account_from_key(&key), Some(AccountId32([7u8; 32])));
+        assert_eq!(account_from_key(&[1; 31]), None);
+    }
+
+    #[test]
+    fn test_u16_to_fraction() {
+        assert_eq!(u16_to_fraction(0), 0.0);
+        assert_eq!(u16_to_fraction(u16::MAX), 1.0);
+    }
+}
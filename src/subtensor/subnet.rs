@@ -0,0 +1,143 @@
+use subxt::utils::AccountId32;
+
+use crate::subtensor::{queries::u16_to_fraction, units::Balance};
+
+/// summary of one subnet as shown by `subnet list`
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubnetInfo {
+    pub netuid: u16,
+    pub n: u16,
+    pub max_allowed_uids: u16,
+    pub tempo: u16,
+    pub emission: Balance,
+    pub burn: Balance,
+    pub difficulty: u64,
+    pub owner: Option<AccountId32>,
+    pub registered_at: u64,
+}
+
+/// per-subnet settings, raw values as stored on chain unless the type says otherwise
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Hyperparameters {
+    pub rho: u16,
+    pub kappa: u16,
+    pub immunity_period: u16,
+    pub min_allowed_weights: u16,
+    pub max_weights_limit: u16,
+    pub tempo: u16,
+    pub min_difficulty: u64,
+    pub max_difficulty: u64,
+    pub difficulty: u64,
+    pub weights_version_key: u64,
+    pub weights_set_rate_limit: u64,
+    pub adjustment_interval: u16,
+    pub activity_cutoff: u16,
+    pub registration_allowed: bool,
+    pub pow_registration_allowed: bool,
+    pub target_registrations_per_interval: u16,
+    pub min_burn: Balance,
+    pub max_burn: Balance,
+    pub bonds_moving_average: u64,
+    pub max_registrations_per_block: u16,
+    pub serving_rate_limit: u64,
+    pub max_validators: u16,
+    pub adjustment_alpha: u64,
+    pub max_allowed_uids: u16,
+    pub scaling_law_power: u16,
+    pub validator_prune_len: u64,
+    pub modality: u16,
+}
+
+impl Hyperparameters {
+    /// name and value of every parameter, in the order they are printed
+    pub fn rows(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("rho", self.rho.to_string()),
+            (
+                "kappa",
+                format!("{} ({:.4})", self.kappa, u16_to_fraction(self.kappa)),
+            ),
+            ("immunity_period", self.immunity_period.to_string()),
+            ("min_allowed_weights", self.min_allowed_weights.to_string()),
+            (
+                "max_weights_limit",
+                format!(
+                    "{} ({:.4})",
+                    self.max_weights_limit,
+                    u16_to_fraction(self.max_weights_limit)
+                ),
+            ),
+            ("tempo", self.tempo.to_string()),
+            ("min_difficulty", self.min_difficulty.to_string()),
+            ("max_difficulty", self.max_difficulty.to_string()),
+            ("difficulty", self.difficulty.to_string()),
+            ("weights_version_key", self.weights_version_key.to_string()),
+            (
+                "weights_set_rate_limit",
+                self.weights_set_rate_limit.to_string(),
+            ),
+            ("adjustment_interval", self.adjustment_interval.to_string()),
+            ("activity_cutoff", self.activity_cutoff.to_string()),
+            (
+                "registration_allowed",
+                self.registration_allowed.to_string(),
+            ),
+            (
+                "pow_registration_allowed",
+                self.pow_registration_allowed.to_string(),
+            ),
+            (
+                "target_registrations_per_interval",
+                self.target_registrations_per_interval.to_string(),
+            ),
+            ("min_burn", self.min_burn.to_string()),
+            ("max_burn", self.max_burn.to_string()),
+            (
+                "bonds_moving_average",
+                self.bonds_moving_average.to_string(),
+            ),
+            (
+                "max_registrations_per_block",
+                self.max_registrations_per_block.to_string(),
+            ),
+            ("serving_rate_limit", self.serving_rate_limit.to_string()),
+            ("max_validators", self.max_validators.to_string()),
+            ("adjustment_alpha", self.adjustment_alpha.to_string()),
+            ("max_allowed_uids", self.max_allowed_uids.to_string()),
+            ("scaling_law_power", self.scaling_law_power.to_string()),
+            ("validator_prune_len", self.validator_prune_len.to_string()),
+            ("modality", self.modality.to_string()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hyperparameter_rows() {
+        let hyperparameters = Hyperparameters {
+            kappa: 32_767,
+            min_burn: Balance::from_tao(1).unwrap(),
+            registration_allowed: true,
+            difficulty: 10_000_000,
+            ..Default::default()
+        };
+
+        let rows = hyperparameters.rows();
+        let value = |name: &str| {
+            rows.iter()
+                .find(|(row, _)| *row == name)
+                .map(|(_, value)| value.clone())
+                .unwrap()
+        };
+
+        assert_eq!(rows.len(), 27);
+        assert_eq!(value("kappa"), "32767 (0.5000)");
+        assert_eq!(value("min_burn"), Balance::from_tao(1).unwrap().to_string());
+        assert_eq!(value("registration_allowed"), "true");
+        assert_eq!(value("rho"), "0");
+        assert_eq!(value("difficulty"), "10000000");
+    }
+}
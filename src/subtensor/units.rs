@@ -0,0 +1,178 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+/// number of rao in one TAO
+pub const RAO_PER_TAO: u64 = 1_000_000_000;
+
+/// an amount of TAO, stored in rao so it never loses precision, serialized as rao
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct Balance(u64);
+
+impl Balance {
+    pub const ZERO: Balance = Balance(0);
+
+    pub const fn from_rao(rao: u64) -> Self {
+        Balance(rao)
+    }
+
+    /// whole TAO, `None` on overflow
+    pub const fn from_tao(tao: u64) -> Option<Self> {
+        match tao.checked_mul(RAO_PER_TAO) {
+            Some(rao) => Some(Balance(rao)),
+            None => None,
+        }
+    }
+
+    pub const fn rao(self) -> u64 {
+        self.0
+    }
+
+    pub const fn is_zero(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn checked_add(self, other: Balance) -> Option<Balance> {
+        self.0.checked_add(other.0).map(Balance)
+    }
+
+    pub fn checked_sub(self, other: Balance) -> Option<Balance> {
+        self.0.checked_sub(other.0).map(Balance)
+    }
+
+    pub fn saturating_add(self, other: Balance) -> Balance {
+        Balance(self.0.saturating_add(other.0))
+    }
+
+    pub fn saturating_sub(self, other: Balance) -> Balance {
+        Balance(self.0.saturating_sub(other.0))
+    }
+
+    /// total of all `balances`, `None` on overflow
+    pub fn checked_sum(balances: impl IntoIterator<Item = Balance>) -> Option<Balance> {
+        balances
+            .into_iter()
+            .try_fold(Balance::ZERO, Balance::checked_add)
+    }
+}
+
+impl fmt::Display for Balance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "τ{}.{:09}", self.0 / RAO_PER_TAO, self.0 % RAO_PER_TAO)
+    }
+}
+
+/// parses "1.5", "τ1.5" or "1.5tao" as TAO and "1500000000rao" as rao
+impl FromStr for Balance {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let lower = s.to_ascii_lowercase();
+
+        if let Some(rao) = lower.strip_suffix("rao") {
+            let rao = rao.trim();
+            if rao.is_empty() || !rao.chars().all(|c| c.is_ascii_digit()) {
+                return Err(format!("Invalid rao amount: {s}"));
+            }
+            return rao
+                .parse()
+                .map(Balance)
+                .map_err(|_| format!("Rao amount too large: {s}"));
+        }
+
+        let tao = lower.strip_suffix("tao").unwrap_or(&lower);
+        let tao = tao.strip_prefix('τ').unwrap_or(tao).trim();
+        parse_tao(tao).map(Balance).map_err(|e| format!("{e}: {s}"))
+    }
+}
+
+/// parses a decimal TAO amount into rao
+fn parse_tao(s: &str) -> Result<u64, &'static str> {
+    let (whole, fraction) = s.split_once('.').unwrap_or((s, ""));
+
+    if whole.is_empty() && fraction.is_empty() {
+        return Err("Invalid TAO amount");
+    }
+    if !whole
+        .chars()
+        .chain(fraction.chars())
+        .all(|c| c.is_ascii_digit())
+    {
+        return Err("Invalid TAO amount");
+    }
+    if fraction.len() > 9 {
+        return Err("TAO amounts have at most 9 decimals");
+    }
+
+    let whole: u64 = if whole.is_empty() {
+        0
+    } else {
+        whole.parse().map_err(|_| "TAO amount too large")?
+    };
+    let fraction: u64 = format!("{fraction:0<9}")
+        .parse()
+        .map_err(|_| "Invalid TAO amount")?;
+
+    whole
+        .checked_mul(RAO_PER_TAO)
+        .and_then(|rao| rao.checked_add(fraction))
+        .ok_or("TAO amount too large")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_balance() {
+        let parse = |s: &str| s.parse::<Balance>().map(Balance::rao);
+
+        assert_eq!(parse("1").unwrap(), RAO_PER_TAO);
+        assert_eq!(parse("1.5").unwrap(), 1_500_000_000);
+        assert_eq!(parse("0.000000001").unwrap(), 1);
+        assert_eq!(parse(".25").unwrap(), 250_000_000);
+        assert_eq!(parse("1.5tao").unwrap(), 1_500_000_000);
+        assert_eq!(parse("1.5 TAO").unwrap(), 1_500_000_000);
+        assert_eq!(parse("τ1.5").unwrap(), 1_500_000_000);
+        assert_eq!(parse("1500000000rao").unwrap(), 1_500_000_000);
+        assert_eq!(parse("18446744073709551615rao").unwrap(), u64::MAX);
+
+        assert!(parse("0.0000000001").is_err());
+        assert!(parse("-1").is_err());
+        assert!(parse("abc").is_err());
+        assert!(parse(".").is_err());
+        assert!(parse("18446744074").is_err());
+        assert!(parse("1.5rao").is_err());
+        assert!(parse("rao").is_err());
+        assert!(parse("18446744073709551616rao").is_err());
+    }
+
+    #[test]
+    fn test_display_balance() {
+        assert_eq!(Balance::from_rao(1_500_000_000).to_string(), "τ1.500000000");
+        assert_eq!(Balance::from_rao(1).to_string(), "τ0.000000001");
+        assert_eq!(Balance::from_tao(2).unwrap().to_string(), "τ2.000000000");
+        assert!(Balance::from_tao(u64::MAX).is_none());
+    }
+
+    #[test]
+    fn test_balance_arithmetic() {
+        let one = Balance::from_tao(1).unwrap();
+        let max = Balance::from_rao(u64::MAX);
+
+        assert_eq!(one.checked_sub(max), None);
+        assert_eq!(max.checked_add(one), None);
+        assert_eq!(max.saturating_add(one), max);
+        assert_eq!(one.saturating_sub(max), Balance::ZERO);
+        assert_eq!(
+            Balance::checked_sum([one, one]).map(Balance::rao),
+            Some(2 * RAO_PER_TAO)
+        );
+        assert_eq!(Balance::checked_sum([]), Some(Balance::ZERO));
+        assert_eq!(Balance::checked_sum([one, max]), None);
+    }
+}
@@ -0,0 +1,122 @@
+/// scales weights so the largest becomes `u16::MAX`, the way the chain expects them
+pub fn normalize_weights(weights: &[f64]) -> Result<Vec<u16>, String> {
+    if let Some(weight) = weights.iter().find(|w| !w.is_finite() || **w < 0.0) {
+        return Err(format!("Weights must be finite and non-negative: {weight}"));
+    }
+
+    let max = weights.iter().copied().fold(0.0, f64::max);
+    if max == 0.0 {
+        return Err("At least one weight must be greater than zero".into());
+    }
+
+    Ok(weights
+        .iter()
+        .map(|w| (w / max * f64::from(u16::MAX)).round() as u16)
+        .collect())
+}
+
+/// first uid or netuid given more than once, the chain rejects weights with duplicates
+pub fn find_duplicate(ids: &[u16]) -> Option<u16> {
+    let mut seen = std::collections::HashSet::new();
+    ids.iter().copied().find(|id| !seen.insert(*id))
+}
+
+/// checks weights the hotkey with `uid` sets against the subnet's `min_allowed_weights` and
+/// `max_weights_limit` the way the pallet does: a weight on itself alone is always allowed and
+/// subnets with fewer than `min_allowed_weights` neurons only need a weight on each of them
+pub fn check_weight_limits(
+    uids: &[u16],
+    weights: &[u16],
+    uid: u16,
+    n: u16,
+    min_allowed_weights: u16,
+    max_weights_limit: u16,
+) -> Result<(), String> {
+    if is_self_weight(uids, uid) {
+        return Ok(());
+    }
+
+    let min_allowed = min_allowed_weights.min(n);
+    if weights.len() < usize::from(min_allowed) {
+        return Err(format!(
+            "Subnet requires at least {min_allowed} weights, got {}",
+            weights.len()
+        ));
+    }
+
+    // the chain compares each weight's share of the total against the limit
+    let total: u64 = weights.iter().map(|w| u64::from(*w)).sum();
+    let max = weights.iter().copied().max().unwrap_or_default();
+    if max_weights_limit < u16::MAX
+        && u64::from(max) * u64::from(u16::MAX) > u64::from(max_weights_limit) * total
+    {
+        return Err(format!(
+            "Largest weight is {:.5} of the total, the subnet allows at most {:.5}",
+            max as f64 / total as f64,
+            f64::from(max_weights_limit) / f64::from(u16::MAX)
+        ));
+    }
+
+    Ok(())
+}
+
+/// a single weight on the hotkey's own uid, which the pallet exempts from the limits
+fn is_self_weight(uids: &[u16], uid: u16) -> bool {
+    uids == [uid]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_weights() {
+        assert_eq!(
+            normalize_weights(&[0.5, 0.25, 0.0]).unwrap(),
+            vec![u16::MAX, 32768, 0]
+        );
+        assert_eq!(normalize_weights(&[3.0]).unwrap(), vec![u16::MAX]);
+        assert!(normalize_weights(&[0.0, 0.0]).is_err());
+        assert!(normalize_weights(&[]).is_err());
+        assert!(normalize_weights(&[1.0, -0.1]).is_err());
+        assert!(normalize_weights(&[f64::NAN]).is_err());
+    }
+
+    #[test]
+    fn test_find_duplicate() {
+        assert_eq!(find_duplicate(&[1, 3, 2]), None);
+        assert_eq!(find_duplicate(&[]), None);
+        assert_eq!(find_duplicate(&[1, 3, 1, 3]), Some(1));
+    }
+
+    #[test]
+    fn test_check_weight_limits() {
+        let check = |uids: &[u16], weights: &[u16], min: u16, limit: u16| {
+            check_weight_limits(uids, weights, 9, 100, min, limit)
+        };
+        assert!(check(&[0, 1], &[u16::MAX, u16::MAX], 2, u16::MAX).is_ok());
+        assert!(check(&[0], &[u16::MAX], 2, u16::MAX).is_err());
+        // zero weights count towards the minimum, as on chain
+        assert!(check(&[0, 1], &[u16::MAX, 0], 2, u16::MAX).is_ok());
+
+        // each weight is half of the total
+        assert!(check(&[0, 1], &[100, 100], 1, u16::MAX / 2 + 1).is_ok());
+        assert!(check(&[0, 1], &[300, 100], 1, u16::MAX / 2).is_err());
+        assert!(check(&[0], &[u16::MAX], 1, u16::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_check_weight_limits_small_subnet() {
+        // a subnet of 3 neurons cannot meet a minimum of 8, weighting all of them is enough
+        assert!(check_weight_limits(&[0, 1, 2], &[1, 1, 1], 0, 3, 8, u16::MAX).is_ok());
+        assert!(check_weight_limits(&[0, 1], &[1, 1], 0, 3, 8, u16::MAX).is_err());
+    }
+
+    #[test]
+    fn test_check_weight_limits_self_weight() {
+        // a weight on itself alone passes any minimum and limit
+        assert!(check_weight_limits(&[4], &[u16::MAX], 4, 256, 8, u16::MAX / 10).is_ok());
+        // on any other uid it does not
+        assert!(check_weight_limits(&[5], &[u16::MAX], 4, 256, 8, u16::MAX / 10).is_err());
+    }
+}